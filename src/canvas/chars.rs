@@ -0,0 +1,39 @@
+use super::*;
+use std::fmt::Display;
+
+/// A grid of [`Cell`]s formatted as the text it renders, without styles.
+///
+/// Rows are separated by newlines.
+///
+/// # Example
+///
+/// ```
+/// # use tender::{canvas::*, grid::*, style::*};
+/// let cells = "abcd".chars().map(|char| Cell { char, ..Cell::default() });
+/// let grid = RowVec1D::new((2, 2), cells.collect()).unwrap();
+///
+/// assert_eq!(Chars(&grid).to_string(), "ab\ncd");
+/// ```
+#[derive(Copy, Clone, Default, Debug)]
+pub struct Chars<T>(pub T);
+
+impl<T: GridRows + Clone> Display for Chars<T>
+where
+    T::Item: AsRef<Cell>,
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        // SAFETY: .. is safe
+        let rows = unsafe { self.0.clone().rows_unchecked(..) };
+
+        for (y, row) in rows.into_iter().enumerate() {
+            if y != 0 {
+                f.write_str("\n")?;
+            }
+            for cell in row {
+                write!(f, "{}", cell.as_ref().char)?;
+            }
+        }
+
+        Ok(())
+    }
+}
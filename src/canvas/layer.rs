@@ -1,7 +1,9 @@
 use super::*;
+use crate::grid::sparse::iter::Occupied;
+use std::convert::TryFrom;
 
 pub struct Layer<G, O: Options = Cell> {
-    pub position: Point,
+    pub position: Point<isize>,
     grid:         G,
     first:        O::First,
 }
@@ -26,7 +28,7 @@ impl<G: Debug> Debug for Layer<G, Damaged> {
 }
 
 impl<G, O: Options> Layer<G, O> {
-    pub fn new(position: impl Into<Point>, grid: G) -> Self {
+    pub fn new(position: impl Into<Point<isize>>, grid: G) -> Self {
         Self {
             position: position.into(),
             grid,
@@ -41,6 +43,52 @@ impl<G, O: Options> Layer<G, O> {
         self.grid.size()
     }
 
+    /// Returns the grid.
+    pub fn grid(&self) -> &G {
+        &self.grid
+    }
+
+    /// Returns the grid mutably, e.g. to push rows to a
+    /// [`RingGrid`](crate::grid::RingGrid).
+    pub fn grid_mut(&mut self) -> &mut G {
+        &mut self.grid
+    }
+
+    /// Returns a formatter printing the text this layer renders.
+    ///
+    /// See [`Chars`].
+    pub fn chars<'a>(&'a self) -> Chars<&'a G>
+    where
+        &'a G: GridRows,
+    {
+        Chars(&self.grid)
+    }
+
+    /// Returns the item at the absolute `point`, or `None` if `point` is not
+    /// on this layer.
+    pub fn item_at<'a>(&'a self, point: impl Into<Point<isize>>) -> Option<<&'a G as Grid>::Item>
+    where
+        &'a G: Grid,
+    {
+        let point = Point::try_from(point.into().saturating_sub(self.position)).ok()?;
+
+        self.grid.item(point)
+    }
+
+    /// Searches for an item that satisfies a predicate, returning it with
+    /// its absolute [`Point`].
+    ///
+    /// Items are searched row by row.
+    pub fn find<'a, P>(&'a self, predicate: P) -> Option<(Point<isize>, <&'a G as Grid>::Item)>
+    where
+        &'a G: GridRows,
+        P: FnMut(&<&'a G as Grid>::Item) -> bool,
+    {
+        let (point, item) = self.grid.find(predicate)?;
+
+        Some((self.position.saturating_add(point.into()), item))
+    }
+
     pub fn frame<'a>(&'a self, rect: impl Index2D) -> Option<Layer<Crop<&'a G>, O>>
     where
         G: WithSize,
@@ -57,13 +105,32 @@ impl<G, O: Options> Layer<G, O> {
         Some(unsafe { Self::frame_mut_unchecked(self, rect.checked(self.size())?) })
     }
 
+    pub fn try_frame<'a>(&'a self, rect: impl Index2D) -> Result<Layer<Crop<&'a G>, O>, GridError>
+    where
+        G: WithSize,
+        &'a G: Grid,
+    {
+        Ok(unsafe { Self::frame_unchecked(self, rect.try_checked(self.size())?) })
+    }
+
+    pub fn try_frame_mut<'a>(
+        &'a mut self,
+        rect: impl Index2D,
+    ) -> Result<Layer<Crop<&'a mut G>, O>, GridError>
+    where
+        G: WithSize,
+        &'a mut G: Grid,
+    {
+        Ok(unsafe { Self::frame_mut_unchecked(self, rect.try_checked(self.size())?) })
+    }
+
     pub unsafe fn frame_unchecked<'a>(&'a self, rect: impl Index2D) -> Layer<Crop<&'a G>, O>
     where
         G: WithSize,
         &'a G: Grid,
     {
         let rect = rect.unchecked(self.size());
-        let position = self.position + rect.start();
+        let position = self.position.saturating_add(rect.start().into());
         let grid = self.grid.crop_unchecked(rect);
 
         Layer {
@@ -82,7 +149,7 @@ impl<G, O: Options> Layer<G, O> {
         &'a mut G: Grid,
     {
         let rect = rect.unchecked(self.size());
-        let position = self.position + rect.start();
+        let position = self.position.saturating_add(rect.start().into());
         let grid = (&mut self.grid).crop_unchecked(rect);
 
         Layer {
@@ -91,8 +158,94 @@ impl<G, O: Options> Layer<G, O> {
             first: self.first,
         }
     }
+
+    /// Composites `self` [`Over`] `bottom` in parallel, one horizontal band
+    /// of `bottom` per thread.
+    ///
+    /// See [`Grid1D::par_chunks_mut()`].
+    pub fn par_over<'t, I, U, B>(&'t self, bottom: &mut Layer<RowGrid1D<I, U>, B>)
+    where
+        G: Sync,
+        &'t G: GridRows,
+        for<'b> <&'t G as Grid>::Item: Over<&'b mut I>,
+        I: Send,
+        U: AsMut<[I]>,
+        B: Options,
+    {
+        self.par_over_bands(bottom, None);
+    }
+
+    /// Composites `self` [`Over`] `bottom` in parallel, in `bands`
+    /// horizontal bands of `bottom` (one per thread if `None`).
+    fn par_over_bands<'t, I, U, B>(
+        &'t self,
+        bottom: &mut Layer<RowGrid1D<I, U>, B>,
+        bands: Option<usize>,
+    ) where
+        G: Sync,
+        &'t G: GridRows,
+        for<'b> <&'t G as Grid>::Item: Over<&'b mut I>,
+        I: Send,
+        U: AsMut<[I]>,
+        B: Options,
+    {
+        let position = self.position.saturating_sub(bottom.position);
+        let grid = &self.grid;
+
+        bottom.grid.par_bands_mut(bands, |y, mut band| {
+            let position = position.saturating_sub(Point { x: 0, y }.into());
+
+            (&mut band)
+                .zip_at(position, grid)
+                .flatten_rows()
+                .for_each(|(bottom, top)| {
+                    top.over(bottom);
+                });
+        });
+    }
 }
 
+impl<I, O: Options> Layer<SparseGrid<I>, O> {
+    /// Returns an iterator over occupied cells with their absolute [`Point`].
+    ///
+    /// Compositing it [`Over`] another layer only costs the number of
+    /// occupied cells, instead of the area of this layer.
+    pub fn occupied(&self) -> OccupiedAt<'_, I> {
+        OccupiedAt {
+            items:    self.grid.iter(),
+            position: self.position,
+        }
+    }
+}
+
+/// An iterator over the occupied cells of a [`Layer`] of a [`SparseGrid`],
+/// with their absolute [`Point`].
+///
+/// See [`Layer::occupied()`].
+#[derive(Clone, Debug)]
+pub struct OccupiedAt<'a, I> {
+    items:    Occupied<'a, I>,
+    position: Point<isize>,
+}
+
+impl<'a, I> Iterator for OccupiedAt<'a, I> {
+    type Item = (Point<isize>, &'a I);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (point, item) = self.items.next()?;
+
+        Some((self.position.saturating_add(point.into()), item))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.items.size_hint()
+    }
+}
+
+/// Composites `self` over `bottom`.
+///
+/// Both positions are absolute: `self` lands at `self.position -
+/// bottom.position` in `bottom`'s grid, and only the overlap is composited.
 impl<'t, 'b, Top, Bottom, T, B> Over<&'b mut Layer<Bottom, B>> for &'t Layer<Top, T>
 where
     T: Options,
@@ -106,7 +259,7 @@ where
     fn over(self, bottom: &'b mut Layer<Bottom, B>) {
         bottom
             .grid
-            .zip_at(self.position, &self.grid)
+            .zip_at(self.position.saturating_sub(bottom.position), &self.grid)
             .flatten_rows()
             .for_each(|(bottom, top)| {
                 top.over(bottom);
@@ -114,6 +267,29 @@ where
     }
 }
 
+impl<'t, 'b, I, Bottom, B> Over<&'b mut Layer<Bottom, B>> for OccupiedAt<'t, I>
+where
+    B: Options,
+    Bottom: WithSize,
+    for<'c> &'c mut Bottom: Grid,
+    for<'c> &'t I: Over<<&'c mut Bottom as Grid>::Item>,
+{
+    type Output = ();
+
+    fn over(self, bottom: &'b mut Layer<Bottom, B>) {
+        for (point, top) in self {
+            let point = match Point::try_from(point.saturating_sub(bottom.position)) {
+                Ok(point) => point,
+                Err(_) => continue,
+            };
+
+            if let Some(bottom) = (&mut bottom.grid).item(point) {
+                top.over(bottom);
+            }
+        }
+    }
+}
+
 impl<'a, G, O> Paint for &'a mut Layer<G, O>
 where
     O: Options,
@@ -134,7 +310,13 @@ where
     &'a G: GridRows<Item = &'a Cell>,
 {
     fn render(self) -> io::Result<()> {
-        render(self.0.position, &self.0.grid, self.1)
+        let (layer, out) = self;
+        let grid = &layer.grid;
+        let (rect, grid_rect) = layer.position.overlap(Size::MAX, grid.size());
+        // SAFETY: the overlap is within the grid
+        let grid = unsafe { grid.crop_unchecked(grid_rect) };
+
+        render(rect.start(), grid, out)
     }
 }
 
@@ -144,12 +326,162 @@ where
 {
     fn render(self) -> io::Result<()> {
         let (layer, out) = self;
+        let grid = &mut layer.grid;
+        let (rect, grid_rect) = layer.position.overlap(Size::MAX, grid.size());
+        // SAFETY: the overlap is within the grid
+        let grid = unsafe { grid.crop_unchecked(grid_rect) };
 
         if layer.first.is_first() {
             layer.first.unset();
-            render(layer.position, &mut layer.grid, out)
+            render(rect.start(), grid, out)
         } else {
-            render_damage(layer.position, &mut layer.grid, out)
+            render_damage(rect.start(), grid, out)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[derive(Copy, Clone, Eq, PartialEq, Debug)]
+    struct Mark(u8);
+
+    impl Over<Mark> for Mark {
+        type Output = Mark;
+
+        fn over(self, bottom: Mark) -> Mark {
+            if self.0 == 0 {
+                bottom
+            } else {
+                self
+            }
+        }
+    }
+
+    type Marks = Layer<RowVec1D<Mark>>;
+
+    struct Hit;
+
+    impl Over<&mut Mark> for &Hit {
+        type Output = ();
+
+        fn over(self, bottom: &mut Mark) {
+            bottom.0 += 1;
+        }
+    }
+
+    fn marks(size: (usize, usize), items: impl IntoIterator<Item = u8>) -> RowVec1D<Mark> {
+        RowVec1D::new(size, items.into_iter().map(Mark).collect()).unwrap()
+    }
+
+    fn cells(size: (usize, usize), chars: &str) -> RowVec1D<Cell> {
+        let cells = chars.chars().map(|char| Cell {
+            char,
+            ..Cell::default()
+        });
+
+        RowVec1D::new(size, cells.collect()).unwrap()
+    }
+
+    #[test]
+    fn over_is_relative_to_bottom() {
+        let top = Marks::new((9, 11), marks((3, 3), 1..=9));
+        let mut bottom = Marks::new((10, 10), marks((4, 3), vec![0; 12]));
+
+        Over::<&mut Marks>::over(&top, &mut bottom);
+
+        #[rustfmt::skip]
+        assert_eq!(bottom.grid().as_ref(), marks((4, 3), vec![
+            0, 0, 0, 0,
+            2, 3, 0, 0,
+            5, 6, 0, 0,
+        ]).as_ref());
+    }
+
+    #[test]
+    fn over_negative_and_disjoint() {
+        let top = Marks::new((-2, -1), marks((3, 2), 1..=6));
+        let mut bottom = Marks::new((0, 0), marks((3, 2), vec![0; 6]));
+
+        Over::<&mut Marks>::over(&top, &mut bottom);
+        assert_eq!(
+            bottom.grid().as_ref(),
+            marks((3, 2), vec![6, 0, 0, 0, 0, 0]).as_ref()
+        );
+
+        let top = Marks::new((isize::MIN, isize::MAX), marks((3, 2), 1..=6));
+        Over::<&mut Marks>::over(&top, &mut bottom);
+        top.par_over(&mut bottom);
+        assert_eq!(
+            bottom.grid().as_ref(),
+            marks((3, 2), vec![6, 0, 0, 0, 0, 0]).as_ref()
+        );
+    }
+
+    #[test]
+    fn occupied_over_costs_occupied_cells() {
+        let mut sparse = SparseGrid::new((100, 100));
+        sparse.insert((0, 0), Hit);
+        sparse.insert((1, 1), Hit);
+        sparse.insert((99, 99), Hit);
+        let top = Layer::<_>::new((-1, 0), sparse);
+        let mut bottom = Marks::new((0, 0), marks((4, 4), vec![0; 16]));
+
+        let occupied = top.occupied();
+        assert_eq!(occupied.size_hint(), (3, Some(3)));
+        Over::<&mut Marks>::over(occupied, &mut bottom);
+
+        let mut expected = vec![0; 16];
+        expected[4] = 1;
+        assert_eq!(bottom.grid().as_ref(), marks((4, 4), expected).as_ref());
+    }
+
+    #[test]
+    fn par_over_matches_over() {
+        let top = Marks::new((1, -1), marks((3, 4), (0..12).map(|i| i % 3)));
+        let bottom = || Marks::new((0, 0), marks((4, 5), 10..30));
+
+        let mut expected = bottom();
+        Over::<&mut Marks>::over(&top, &mut expected);
+        assert_ne!(expected.grid(), bottom().grid());
+
+        for bands in [Some(1), Some(2), Some(3), Some(5), Some(8), Some(100), None] {
+            let mut bottom = bottom();
+            top.par_over_bands(&mut bottom, bands);
+
+            assert_eq!(bottom.grid(), expected.grid(), "{:?} bands", bands);
         }
     }
+
+    #[test]
+    fn item_at_saturates() {
+        let layer = Marks::new((isize::MIN, -1), marks((3, 2), 1..=6));
+
+        assert_eq!(layer.item_at((isize::MIN + 2, 0)), Some(&Mark(6)));
+        assert_eq!(layer.item_at((isize::MAX, 0)), None);
+        assert_eq!(layer.item_at((isize::MIN, -2)), None);
+    }
+
+    #[test]
+    fn render_negative_position() {
+        let layer = Layer::<_>::new((-1, -1), cells((3, 2), "abcdef"));
+        let mut out = Vec::new();
+        (&layer, &mut out).render().unwrap();
+
+        let mut expected = Vec::new();
+        render(Point::ZERO, &cells((2, 1), "ef"), &mut expected).unwrap();
+
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            String::from_utf8(expected).unwrap()
+        );
+
+        let layer = Layer::<_>::new((-3, 0), cells((3, 2), "abcdef"));
+        let mut out = Vec::new();
+        (&layer, &mut out).render().unwrap();
+
+        assert!(out.is_empty());
+    }
 }
@@ -1,6 +1,8 @@
+mod chars;
 mod layer;
 mod screen;
 
+pub use chars::*;
 pub use layer::*;
 pub use screen::*;
 
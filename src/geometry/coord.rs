@@ -1,6 +1,8 @@
 use std::{
     cmp::Ordering,
-    ops::{Add, Range, Sub},
+    convert::{TryFrom, TryInto},
+    num::TryFromIntError,
+    ops::{Add, Div, Mul, Range, Sub},
 };
 
 /// Alias of [`Coord`].
@@ -45,6 +47,52 @@ impl<X: Sub<U>, Y: Sub<V>, U, V> Sub<Coord<U, V>> for Coord<X, Y> {
     }
 }
 
+impl<X: Mul<T>, Y: Mul<T>, T: Copy> Mul<T> for Coord<X, Y> {
+    type Output = Coord<X::Output, Y::Output>;
+
+    fn mul(self, rhs: T) -> Self::Output {
+        Coord {
+            x: self.x * rhs,
+            y: self.y * rhs,
+        }
+    }
+}
+
+impl<X: Div<T>, Y: Div<T>, T: Copy> Div<T> for Coord<X, Y> {
+    type Output = Coord<X::Output, Y::Output>;
+
+    fn div(self, rhs: T) -> Self::Output {
+        Coord {
+            x: self.x / rhs,
+            y: self.y / rhs,
+        }
+    }
+}
+
+impl<X: Ord, Y: Ord> Coord<X, Y> {
+    /// Restricts both axis of `self` between `min` and `max`.
+    ///
+    /// ### Panics
+    ///
+    /// Panics if `min.x > max.x` or `min.y > max.y`.
+    pub fn clamp(self, min: Self, max: Self) -> Self {
+        Self {
+            x: self.x.clamp(min.x, max.x),
+            y: self.y.clamp(min.y, max.y),
+        }
+    }
+}
+
+impl<X, Y> Coord<X, Y> {
+    /// Swaps the x and y axis.
+    pub fn transpose(self) -> Coord<Y, X> {
+        Coord {
+            x: self.y,
+            y: self.x,
+        }
+    }
+}
+
 impl Coord {
     pub const MAX: Self = Point {
         x: usize::MAX,
@@ -68,6 +116,82 @@ impl Coord {
             y: self.y.min(other.y),
         }
     }
+
+    /// Returns the bounding [`Coord`].
+    pub fn max(self, other: Size) -> Self {
+        Self {
+            x: self.x.max(other.x),
+            y: self.y.max(other.y),
+        }
+    }
+}
+
+impl Coord<isize> {
+    /// Creates a signed [`Rect`] from the signed [`Point`] `self` with
+    /// `size`.
+    ///
+    /// Ends saturate at `isize::MAX`.
+    pub fn rect(self, size: Size) -> Coord<Range<isize>> {
+        Coord {
+            x: self.x..self.x.saturating_add(saturate(size.x)),
+            y: self.y..self.y.saturating_add(saturate(size.y)),
+        }
+    }
+
+    /// Adds `other` to `self`, saturating at the numeric bounds.
+    pub fn saturating_add(self, other: Self) -> Self {
+        Self {
+            x: self.x.saturating_add(other.x),
+            y: self.y.saturating_add(other.y),
+        }
+    }
+
+    /// Subtracts `other` from `self`, saturating at the numeric bounds.
+    pub fn saturating_sub(self, other: Self) -> Self {
+        Self {
+            x: self.x.saturating_sub(other.x),
+            y: self.y.saturating_sub(other.y),
+        }
+    }
+
+    /// Returns where `((0, 0), size)` and `(self, other)` overlap, relative
+    /// to `(0, 0)` and to `self` respectively.
+    ///
+    /// Both [`Rect`]s have the same size, which is zero when the rects do not
+    /// overlap.
+    pub fn overlap(self, size: Size, other: Size) -> (Rect, Rect) {
+        let rect = self.rect(other).crop(size);
+        let start = Point {
+            x: saturate(rect.x.start)
+                .saturating_sub(self.x)
+                .clamp(0, saturate(other.x)) as usize,
+            y: saturate(rect.y.start)
+                .saturating_sub(self.y)
+                .clamp(0, saturate(other.y)) as usize,
+        };
+        let other = start.rect(rect.size());
+
+        (rect, other)
+    }
+}
+
+impl Coord<Range<isize>> {
+    /// Crops `self` to fit in `((0, 0), size)`.
+    ///
+    /// The returned [`Rect`] is empty when `self` is entirely outside.
+    pub fn crop(&self, size: Size) -> Rect {
+        fn crop(range: &Range<isize>, len: usize) -> Range<usize> {
+            let end = range.end.clamp(0, saturate(len));
+            let start = range.start.clamp(0, end);
+
+            start as usize..end as usize
+        }
+
+        Rect {
+            x: crop(&self.x, size.x),
+            y: crop(&self.y, size.y),
+        }
+    }
 }
 
 impl Rect {
@@ -132,6 +256,32 @@ impl<X: PartialOrd, Y: PartialOrd> PartialOrd for Coord<X, Y> {
     }
 }
 
+/// Coordinates above `isize::MAX` saturate.
+impl From<Point> for Point<isize> {
+    fn from(point: Point) -> Self {
+        Self {
+            x: saturate(point.x),
+            y: saturate(point.y),
+        }
+    }
+}
+
+/// Converts `n` to `isize`, saturating at `isize::MAX`.
+fn saturate(n: usize) -> isize {
+    isize::try_from(n).unwrap_or(isize::MAX)
+}
+
+impl TryFrom<Point<isize>> for Point {
+    type Error = TryFromIntError;
+
+    fn try_from(point: Point<isize>) -> Result<Self, Self::Error> {
+        Ok(Self {
+            x: point.x.try_into()?,
+            y: point.y.try_into()?,
+        })
+    }
+}
+
 impl<X, Y> From<Coord<X, Y>> for (X, Y) {
     fn from(coord: Coord<X, Y>) -> Self {
         (coord.x, coord.y)
@@ -146,3 +296,96 @@ impl<X, Y> From<(X, Y)> for Coord<X, Y> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn from_saturates() {
+        let point = Point::<isize>::from(Point {
+            x: usize::MAX,
+            y: 1,
+        });
+
+        assert_eq!(point, Point {
+            x: isize::MAX,
+            y: 1,
+        });
+    }
+
+    #[test]
+    fn saturating_ops() {
+        let point = Point::<isize> {
+            x: isize::MIN,
+            y: isize::MAX,
+        };
+
+        assert_eq!(point.saturating_sub(Point { x: 1, y: -1 }), point);
+        assert_eq!(point.saturating_add(Point { x: -1, y: 1 }), point);
+        assert_eq!(point.saturating_add(Point { x: 1, y: -1 }), Point {
+            x: isize::MIN + 1,
+            y: isize::MAX - 1,
+        });
+    }
+
+    #[test]
+    fn signed_rect_crop() {
+        let size = Size { x: 10, y: 10 };
+
+        let rect = Point::<isize> { x: -2, y: 8 }.rect(Size { x: 4, y: 4 });
+        assert_eq!(rect, Coord { x: -2..2, y: 8..12 });
+        assert_eq!(rect.crop(size), Rect { x: 0..2, y: 8..10 });
+
+        let rect = Point::<isize> { x: -5, y: 20 }.rect(Size { x: 4, y: 4 });
+        assert!(rect.crop(size).is_empty());
+
+        let rect = Point {
+            x: isize::MAX,
+            y: 0,
+        }
+        .rect(Size::MAX);
+        assert_eq!(rect.x, isize::MAX..isize::MAX);
+    }
+
+    #[test]
+    fn overlap() {
+        let size = Size { x: 10, y: 10 };
+        let other = Size { x: 4, y: 4 };
+
+        let (rect, other_rect) = Point { x: -2, y: 8 }.overlap(size, other);
+        assert_eq!(rect, Rect { x: 0..2, y: 8..10 });
+        assert_eq!(other_rect, Rect { x: 2..4, y: 0..2 });
+
+        let (rect, other_rect) = Point { x: 20, y: 0 }.overlap(size, other);
+        assert!(rect.is_empty() && other_rect.is_empty());
+    }
+
+    #[test]
+    fn overlap_extremes() {
+        let (rect, other) = Point {
+            x: isize::MAX,
+            y: 0,
+        }
+        .overlap(Size::MAX, Size::MAX);
+        assert_eq!(rect.size(), other.size());
+        assert_eq!(rect.x, isize::MAX as usize..isize::MAX as usize);
+
+        let (rect, other) = Point {
+            x: isize::MIN,
+            y: isize::MIN,
+        }
+        .overlap(Size::MAX, Size::MAX);
+        assert_eq!(rect.size(), other.size());
+        assert_eq!(rect.start(), Point::ZERO);
+        assert_eq!(other.start(), Point {
+            x: isize::MAX as usize,
+            y: isize::MAX as usize,
+        });
+
+        let (rect, other) = Point { x: -1, y: -1 }.overlap(Size { x: 3, y: 3 }, Size::MAX);
+        assert_eq!(rect, Rect { x: 0..3, y: 0..3 });
+        assert_eq!(other, Rect { x: 1..4, y: 1..4 });
+    }
+}
@@ -0,0 +1,354 @@
+//! Constraint-based splitting of [`Rect`]s.
+//!
+//! A [`Layout`] splits a [`Rect`] along a [`Direction`] into as many
+//! [`Rect`]s as it has [`Constraint`]s, with optional spacing between them and
+//! margins around them. The resulting [`Rect`]s can be split again by other
+//! layouts, or used to frame a layer or a screen.
+//!
+//! Rounding is deterministic and the pieces (with spacing) always sum to the
+//! parent (without margins):
+//!
+//! 1. Each constraint starts with a base length:
+//!    [`Length`](Constraint::Length), [`Percentage`](Constraint::Percentage),
+//!    [`Ratio`](Constraint::Ratio) and [`Min`](Constraint::Min) their own,
+//!    [`Max`](Constraint::Max) and [`Fill`](Constraint::Fill) zero.
+//! 2. If base lengths overflow the available length, they are all shrunk
+//!    proportionally.
+//! 3. Otherwise, the remaining length is shared between
+//!    [`Min`](Constraint::Min) (weight 1), [`Max`](Constraint::Max) (weight 1,
+//!    up to their maximum) and [`Fill`](Constraint::Fill) (their weight).
+//! 4. Any length still remaining goes to the last piece.
+//!
+//! Shares are rounded down, then the leftover units go one by one to the
+//! largest fractional parts, the first pieces winning ties.
+
+use super::*;
+use std::ops::Range;
+
+/// The axis along which a [`Layout`] splits.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum Direction {
+    /// Splits into columns, left to right.
+    Horizontal,
+    /// Splits into rows, top to bottom.
+    Vertical,
+}
+
+/// The length of a piece of a [`Layout`].
+///
+/// See the [module docs](self) for how lengths are resolved.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum Constraint {
+    /// Exactly `n` units.
+    Length(usize),
+    /// `n`% of the available length.
+    Percentage(usize),
+    /// `num / den` of the available length.
+    Ratio(usize, usize),
+    /// At least `n` units, growing with the remaining length.
+    Min(usize),
+    /// At most `n` units, growing with the remaining length.
+    Max(usize),
+    /// A share of the remaining length, proportional to its weight.
+    Fill(usize),
+}
+
+impl Constraint {
+    fn base(self, available: usize) -> usize {
+        match self {
+            Self::Length(n) | Self::Min(n) => n,
+            Self::Percentage(p) => fraction(available, p, 100),
+            Self::Ratio(_, 0) => 0,
+            Self::Ratio(num, den) => fraction(available, num, den),
+            Self::Max(_) | Self::Fill(_) => 0,
+        }
+    }
+
+    fn weight(self) -> usize {
+        match self {
+            Self::Min(_) | Self::Max(_) => 1,
+            Self::Fill(weight) => weight,
+            _ => 0,
+        }
+    }
+
+    fn max(self) -> usize {
+        match self {
+            Self::Max(n) => n,
+            _ => usize::MAX,
+        }
+    }
+}
+
+/// Splits [`Rect`]s according to [`Constraint`]s.
+///
+/// See the [module docs](self).
+///
+/// # Example
+///
+/// ```
+/// # use tender::geometry::{layout::*, *};
+/// let screen = Point::ZERO.rect(Size { x: 80, y: 24 });
+///
+/// let rows = Layout::vertical([Constraint::Length(1), Constraint::Fill(1)]).split(&screen);
+/// let cols = Layout::horizontal([Constraint::Percentage(25), Constraint::Fill(1)])
+///     .spacing(1)
+///     .split(&rows[1]);
+///
+/// assert_eq!(rows[0], Rect { x: 0..80, y: 0..1 });
+/// assert_eq!(cols[0], Rect { x: 0..19, y: 1..24 });
+/// assert_eq!(cols[1], Rect { x: 20..80, y: 1..24 });
+/// ```
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+pub struct Layout {
+    direction:   Direction,
+    constraints: Vec<Constraint>,
+    spacing:     usize,
+    margins:     Margins,
+}
+
+impl Layout {
+    /// Creates a new [`Layout`] splitting along `direction`.
+    pub fn new(direction: Direction, constraints: impl IntoIterator<Item = Constraint>) -> Self {
+        Self {
+            direction,
+            constraints: constraints.into_iter().collect(),
+            spacing: 0,
+            margins: Margins::default(),
+        }
+    }
+
+    /// Creates a new [`Layout`] splitting into columns.
+    pub fn horizontal(constraints: impl IntoIterator<Item = Constraint>) -> Self {
+        Self::new(Direction::Horizontal, constraints)
+    }
+
+    /// Creates a new [`Layout`] splitting into rows.
+    pub fn vertical(constraints: impl IntoIterator<Item = Constraint>) -> Self {
+        Self::new(Direction::Vertical, constraints)
+    }
+
+    /// Leaves `spacing` units between pieces.
+    ///
+    /// Spacing shrinks when larger than the available length.
+    pub fn spacing(mut self, spacing: usize) -> Self {
+        self.spacing = spacing;
+        self
+    }
+
+    /// Insets the split [`Rect`] by `margins`.
+    pub fn margins(mut self, margins: impl Into<Margins>) -> Self {
+        self.margins = margins.into();
+        self
+    }
+
+    /// Splits `rect` into one [`Rect`] per constraint.
+    pub fn split(&self, rect: &Rect) -> Vec<Rect> {
+        let rect = rect.inset(self.margins);
+        let (range, other) = match self.direction {
+            Direction::Horizontal => (&rect.x, &rect.y),
+            Direction::Vertical => (&rect.y, &rect.x),
+        };
+
+        self.ranges(range.clone())
+            .into_iter()
+            .map(|range| match self.direction {
+                Direction::Horizontal => Rect {
+                    x: range,
+                    y: other.clone(),
+                },
+                Direction::Vertical => Rect {
+                    x: other.clone(),
+                    y: range,
+                },
+            })
+            .collect()
+    }
+
+    fn ranges(&self, range: Range<usize>) -> Vec<Range<usize>> {
+        let count = self.constraints.len();
+        if count == 0 {
+            return Vec::new();
+        }
+
+        let total = range.end - range.start;
+        let gaps = count - 1;
+        let spacing = self.spacing.min(total.checked_div(gaps).unwrap_or(0));
+        let available = total - spacing * gaps;
+
+        let lengths = lengths(&self.constraints, available);
+        let mut start = range.start;
+
+        lengths
+            .into_iter()
+            .map(|length| {
+                let range = start..start + length;
+                start += length + spacing;
+                range
+            })
+            .collect()
+    }
+}
+
+/// Resolves the lengths of `constraints`, which sum to `available`.
+fn lengths(constraints: &[Constraint], available: usize) -> Vec<usize> {
+    let bases = constraints
+        .iter()
+        .map(|constraint| constraint.base(available))
+        .collect::<Vec<_>>();
+    let sum = bases.iter().sum::<usize>();
+
+    if sum > available {
+        return apportion(available, &bases);
+    }
+
+    let mut lengths = bases;
+    let mut remaining = available - sum;
+
+    // Share the remaining length, capping maximums until nothing moves
+    while remaining != 0 {
+        let weights = constraints
+            .iter()
+            .zip(&lengths)
+            .map(|(constraint, &length)| {
+                if length < constraint.max() {
+                    constraint.weight()
+                } else {
+                    0
+                }
+            })
+            .collect::<Vec<_>>();
+
+        if weights.iter().all(|&weight| weight == 0) {
+            break;
+        }
+
+        let mut moved = 0;
+        for ((length, share), constraint) in lengths
+            .iter_mut()
+            .zip(apportion(remaining, &weights))
+            .zip(constraints)
+        {
+            let grown = (*length + share).min(constraint.max());
+            moved += grown - *length;
+            *length = grown;
+        }
+
+        remaining -= moved;
+    }
+
+    if let Some(last) = lengths.last_mut() {
+        *last += remaining;
+    }
+
+    lengths
+}
+
+/// Splits `total` proportionally to `weights`, with the largest remainder
+/// method.
+/// Returns `len * num / den` without overflowing, saturating at
+/// `usize::MAX`.
+fn fraction(len: usize, num: usize, den: usize) -> usize {
+    let fraction = len as u128 * num as u128 / den as u128;
+
+    fraction.min(usize::MAX as u128) as usize
+}
+
+fn apportion(total: usize, weights: &[usize]) -> Vec<usize> {
+    let sum = weights.iter().sum::<usize>();
+    if sum == 0 {
+        return vec![0; weights.len()];
+    }
+
+    let (mut shares, remainders): (Vec<_>, Vec<_>) = weights
+        .iter()
+        .map(|&weight| {
+            let share = total as u128 * weight as u128;
+
+            ((share / sum as u128) as usize, share % sum as u128)
+        })
+        .unzip();
+
+    let mut order = (0..weights.len()).collect::<Vec<_>>();
+    order.sort_by(|&a, &b| remainders[b].cmp(&remainders[a]));
+
+    let leftover = total - shares.iter().sum::<usize>();
+    for &i in order.iter().take(leftover) {
+        shares[i] += 1;
+    }
+
+    shares
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Constraint::*, *};
+    use pretty_assertions::assert_eq;
+
+    fn lens(constraints: &[Constraint], total: usize) -> Vec<usize> {
+        Layout::horizontal(constraints.iter().copied())
+            .split(&Point::ZERO.rect(Size { x: total, y: 1 }))
+            .into_iter()
+            .map(|rect| rect.size().x)
+            .collect()
+    }
+
+    #[test]
+    fn sums_to_parent() {
+        let constraints = [
+            &[Length(3), Percentage(50), Fill(1)][..],
+            &[Ratio(1, 3), Ratio(1, 3), Ratio(1, 3)],
+            &[Min(5), Max(2), Fill(2)],
+            &[Length(10), Length(10)],
+            &[Percentage(0)],
+        ];
+
+        for constraints in &constraints {
+            for total in 0..40 {
+                let sum = lens(constraints, total).iter().sum::<usize>();
+
+                assert_eq!(sum, total, "{:?} in {}", constraints, total);
+            }
+        }
+    }
+
+    #[test]
+    fn rounding() {
+        assert_eq!(lens(&[Ratio(1, 3), Ratio(1, 3), Ratio(1, 3)], 10), [3, 3, 4]);
+        assert_eq!(lens(&[Fill(1), Fill(1), Fill(1)], 10), [4, 3, 3]);
+        assert_eq!(lens(&[Fill(1), Fill(2)], 10), [3, 7]);
+        assert_eq!(lens(&[Length(6), Length(6)], 10), [5, 5]);
+    }
+
+    #[test]
+    fn min_max() {
+        assert_eq!(lens(&[Length(2), Min(3)], 10), [2, 8]);
+        assert_eq!(lens(&[Max(3), Fill(1)], 10), [3, 7]);
+        assert_eq!(lens(&[Max(3), Max(3)], 10), [3, 7]);
+        assert_eq!(lens(&[Min(4), Max(20)], 10), [7, 3]);
+    }
+
+    #[test]
+    fn large_constraints() {
+        assert_eq!(lens(&[Percentage(usize::MAX)], 10), [10]);
+        assert_eq!(lens(&[Ratio(usize::MAX, 1), Fill(1)], 10), [10, 0]);
+        assert_eq!(lens(&[Ratio(usize::MAX, usize::MAX), Length(3)], 10), [8, 2]);
+        assert_eq!(lens(&[Percentage(usize::MAX), Percentage(usize::MAX)], 10), [5, 5]);
+    }
+
+    #[test]
+    fn spacing_and_margins() {
+        let rects = Layout::vertical([Fill(1), Fill(1)])
+            .spacing(2)
+            .margins(1)
+            .split(&Point::ZERO.rect(Size { x: 10, y: 10 }));
+
+        assert_eq!(rects, [Rect { x: 1..9, y: 1..4 }, Rect { x: 1..9, y: 6..9 }]);
+
+        let rects = Layout::horizontal([Fill(1), Fill(1), Fill(1)])
+            .spacing(5)
+            .split(&Point::ZERO.rect(Size { x: 4, y: 1 }));
+
+        assert_eq!(rects.last().unwrap().x.end, 4);
+    }
+}
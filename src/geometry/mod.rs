@@ -1,3 +1,7 @@
+pub mod layout;
+
 mod coord;
+mod rect;
 
 pub use coord::*;
+pub use rect::*;
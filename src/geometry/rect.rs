@@ -0,0 +1,355 @@
+use super::*;
+use std::ops::Range;
+
+/// Margins around the sides of a [`Rect`].
+///
+/// Converts from a `usize` (all sides) or a [`Size`] (`x` on the left and
+/// right, `y` on the top and bottom).
+///
+/// See [`Rect::inset()`], [`Rect::outset()`].
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Default, Debug)]
+pub struct Margins {
+    pub top:    usize,
+    pub right:  usize,
+    pub bottom: usize,
+    pub left:   usize,
+}
+
+impl From<usize> for Margins {
+    fn from(margin: usize) -> Self {
+        Self {
+            top:    margin,
+            right:  margin,
+            bottom: margin,
+            left:   margin,
+        }
+    }
+}
+
+impl From<Size> for Margins {
+    fn from(Size { x, y }: Size) -> Self {
+        Self {
+            top:    y,
+            right:  x,
+            bottom: y,
+            left:   x,
+        }
+    }
+}
+
+impl From<(usize, usize)> for Margins {
+    fn from(size: (usize, usize)) -> Self {
+        Size::from(size).into()
+    }
+}
+
+impl Rect {
+    /// Returns whether `self` has no area.
+    pub fn is_empty(&self) -> bool {
+        self.x.start >= self.x.end || self.y.start >= self.y.end
+    }
+
+    /// Returns whether `point` is in `self`.
+    pub fn contains(&self, point: impl Into<Point>) -> bool {
+        let point = point.into();
+
+        self.x.contains(&point.x) && self.y.contains(&point.y)
+    }
+
+    /// Returns the area common to `self` and `other`, or [`None`] if they do
+    /// not overlap.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use tender::geometry::*;
+    /// let a = Point::ZERO.rect(Size { x: 4, y: 4 });
+    /// let b = Point::<usize> { x: 2, y: 3 }.rect(Size { x: 4, y: 4 });
+    ///
+    /// let c = Point::<usize> { x: 4, y: 0 }.rect(Size::ONE);
+    ///
+    /// assert_eq!(a.intersection(&b), Some(Rect { x: 2..4, y: 3..4 }));
+    /// assert_eq!(a.intersection(&c), None);
+    /// ```
+    pub fn intersection(&self, other: &Rect) -> Option<Rect> {
+        let rect = Rect {
+            x: self.x.start.max(other.x.start)..self.x.end.min(other.x.end),
+            y: self.y.start.max(other.y.start)..self.y.end.min(other.y.end),
+        };
+
+        if rect.is_empty() {
+            None
+        } else {
+            Some(rect)
+        }
+    }
+
+    /// Returns the smallest [`Rect`] containing both `self` and `other`.
+    ///
+    /// Empty rects are ignored.
+    pub fn union(&self, other: &Rect) -> Rect {
+        if self.is_empty() {
+            other.clone()
+        } else if other.is_empty() {
+            self.clone()
+        } else {
+            Rect {
+                x: self.x.start.min(other.x.start)..self.x.end.max(other.x.end),
+                y: self.y.start.min(other.y.start)..self.y.end.max(other.y.end),
+            }
+        }
+    }
+
+    /// Splits `self` in two at the column `x`, relative to `self`'s start.
+    ///
+    /// ### Panics
+    ///
+    /// Panics if `x > width`.
+    pub fn split_at_x(&self, x: usize) -> (Rect, Rect) {
+        let (left, right) = split(&self.x, x);
+
+        (
+            Rect {
+                x: left,
+                y: self.y.clone(),
+            },
+            Rect {
+                x: right,
+                y: self.y.clone(),
+            },
+        )
+    }
+
+    /// Splits `self` in two at the row `y`, relative to `self`'s start.
+    ///
+    /// ### Panics
+    ///
+    /// Panics if `y > height`.
+    pub fn split_at_y(&self, y: usize) -> (Rect, Rect) {
+        let (top, bottom) = split(&self.y, y);
+
+        (
+            Rect {
+                x: self.x.clone(),
+                y: top,
+            },
+            Rect {
+                x: self.x.clone(),
+                y: bottom,
+            },
+        )
+    }
+
+    /// Shrinks `self` by `margins`.
+    ///
+    /// Margins larger than `self` leave an empty [`Rect`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use tender::geometry::*;
+    /// let rect = Point::ZERO.rect(Size { x: 10, y: 4 });
+    ///
+    /// assert_eq!(rect.inset((2, 1)), Rect { x: 2..8, y: 1..3 });
+    /// assert_eq!(rect.inset(3), Rect { x: 3..7, y: 1..1 });
+    /// ```
+    pub fn inset(&self, margins: impl Into<Margins>) -> Rect {
+        let Margins {
+            top,
+            right,
+            bottom,
+            left,
+        } = margins.into();
+
+        Rect {
+            x: inset(&self.x, left, right),
+            y: inset(&self.y, top, bottom),
+        }
+    }
+
+    /// Grows `self` by `margins`.
+    ///
+    /// Stops at `(0, 0)` on the top and left sides.
+    pub fn outset(&self, margins: impl Into<Margins>) -> Rect {
+        let Margins {
+            top,
+            right,
+            bottom,
+            left,
+        } = margins.into();
+
+        Rect {
+            x: self.x.start.saturating_sub(left)..self.x.end + right,
+            y: self.y.start.saturating_sub(top)..self.y.end + bottom,
+        }
+    }
+
+    /// Returns a [`Rect`] of `size` centered in `self`.
+    ///
+    /// `size` is cropped to fit in `self`. When it cannot be exactly centered,
+    /// it leans towards the top left.
+    pub fn center(&self, size: Size) -> Rect {
+        let size = size.min(self.size());
+        let start = self.start() + (self.size() - size) / 2;
+
+        start.rect(size)
+    }
+
+    /// Returns an iterator over the [`Point`]s of `self`, row by row.
+    pub fn points(&self) -> Points {
+        Points::new(self.clone())
+    }
+}
+
+/// An iterator over the [`Point`]s of a [`Rect`], row by row.
+///
+/// See [`Rect::points()`].
+#[derive(Clone, Debug)]
+pub struct Points {
+    rect:  Rect,
+    point: Point,
+}
+
+impl Points {
+    fn new(rect: Rect) -> Self {
+        let point = if rect.is_empty() {
+            rect.end()
+        } else {
+            rect.start()
+        };
+
+        Self { rect, point }
+    }
+}
+
+impl Iterator for Points {
+    type Item = Point;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.point.y >= self.rect.y.end {
+            return None;
+        }
+
+        let point = self.point;
+
+        self.point.x += 1;
+        if self.point.x >= self.rect.x.end {
+            self.point.x = self.rect.x.start;
+            self.point.y += 1;
+        }
+
+        Some(point)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = if self.point.y >= self.rect.y.end {
+            0
+        } else {
+            let width = self.rect.x.end - self.rect.x.start;
+
+            (self.rect.y.end - self.point.y) * width - (self.point.x - self.rect.x.start)
+        };
+
+        (len, Some(len))
+    }
+}
+
+impl ExactSizeIterator for Points {}
+
+fn split(range: &Range<usize>, at: usize) -> (Range<usize>, Range<usize>) {
+    assert!(at <= range.end - range.start, "Split index out of bounds");
+
+    let mid = range.start + at;
+
+    (range.start..mid, mid..range.end)
+}
+
+fn inset(range: &Range<usize>, start: usize, end: usize) -> Range<usize> {
+    let end = range.end.saturating_sub(end).max(range.start);
+    let start = (range.start + start).min(end);
+
+    start..end
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    fn rect(x: Range<usize>, y: Range<usize>) -> Rect {
+        Rect { x, y }
+    }
+
+    #[test]
+    fn intersection_union() {
+        let (a, b) = (rect(0..4, 0..4), rect(4..6, 0..4));
+        let empty = rect(2..2, 1..3);
+
+        assert_eq!(a.intersection(&b), None);
+        assert_eq!(a.intersection(&empty), None);
+        assert_eq!(empty.intersection(&empty), None);
+        assert_eq!(a.intersection(&a), Some(a.clone()));
+
+        assert_eq!(a.union(&b), rect(0..6, 0..4));
+        assert_eq!(a.union(&empty), a);
+        assert_eq!(empty.union(&b), b);
+        assert_eq!(empty.union(&rect(5..5, 5..9)), rect(5..5, 5..9));
+    }
+
+    #[test]
+    fn split() {
+        let whole = rect(2..5, 1..3);
+
+        assert_eq!(whole.split_at_x(0), (rect(2..2, 1..3), whole.clone()));
+        assert_eq!(whole.split_at_x(3), (whole.clone(), rect(5..5, 1..3)));
+        assert_eq!(whole.split_at_y(1), (rect(2..5, 1..2), rect(2..5, 2..3)));
+
+        let empty = rect(4..4, 0..0);
+        assert_eq!(empty.split_at_x(0), (empty.clone(), empty.clone()));
+        assert_eq!(empty.split_at_y(0), (empty.clone(), empty.clone()));
+    }
+
+    #[test]
+    #[should_panic(expected = "Split index out of bounds")]
+    fn split_out_of_bounds() {
+        rect(2..5, 1..3).split_at_x(4);
+    }
+
+    #[test]
+    fn inset_outset() {
+        let empty = rect(3..3, 2..2);
+
+        assert_eq!(empty.inset(1), empty);
+        assert_eq!(empty.outset(1), rect(2..4, 1..3));
+        assert_eq!(rect(0..1, 0..5).inset((1, 0)), rect(0..0, 0..5));
+        assert_eq!(rect(1..3, 1..3).outset(2), rect(0..5, 0..5));
+        assert_eq!(rect(1..3, 1..3).outset((1, 0)), rect(0..4, 1..3));
+    }
+
+    #[test]
+    fn center() {
+        let whole = rect(2..7, 1..3);
+
+        assert_eq!(whole.center(Size { x: 2, y: 2 }), rect(3..5, 1..3));
+        assert_eq!(whole.center(Size { x: 9, y: 9 }), whole);
+        assert_eq!(whole.center(Size::ZERO), rect(4..4, 2..2));
+        assert_eq!(rect(3..3, 0..4).center(Size::ONE), rect(3..3, 1..2));
+    }
+
+    #[test]
+    fn points() {
+        let points = rect(1..3, 4..6).points().collect::<Vec<_>>();
+        let p = |x, y| Point { x, y };
+        assert_eq!(points, [p(1, 4), p(2, 4), p(1, 5), p(2, 5)]);
+
+        let mut points = rect(1..3, 4..6).points();
+        points.next();
+        assert_eq!(points.len(), 3);
+
+        for empty in [rect(0..0, 0..0), rect(2..2, 0..3), rect(0..3, 2..2)] {
+            let mut points = empty.points();
+            assert_eq!(points.len(), 0);
+            assert_eq!(points.next(), None);
+        }
+    }
+}
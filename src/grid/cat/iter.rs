@@ -0,0 +1,40 @@
+//! Iterators for [`HCat`](super::HCat)'s and [`VCat`](super::VCat)'s `Grid*`
+//! implementations.
+
+/// An iterator over either side of a concatenation.
+#[derive(Copy, Clone, Debug)]
+pub enum Either<A, B> {
+    /// Iterates on the first grid.
+    A(A),
+    /// Iterates on the second grid.
+    B(B),
+}
+
+impl<A: Iterator, B: Iterator<Item = A::Item>> Iterator for Either<A, B> {
+    type Item = A::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self {
+            Self::A(a) => a.next(),
+            Self::B(b) => b.next(),
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self {
+            Self::A(a) => a.size_hint(),
+            Self::B(b) => b.size_hint(),
+        }
+    }
+}
+
+impl<A: DoubleEndedIterator, B: DoubleEndedIterator<Item = A::Item>> DoubleEndedIterator
+    for Either<A, B>
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        match self {
+            Self::A(a) => a.next_back(),
+            Self::B(b) => b.next_back(),
+        }
+    }
+}
@@ -0,0 +1,305 @@
+//! Grids that concatenate two grids.
+
+pub mod iter;
+
+use super::*;
+use iter::*;
+use std::{
+    iter::{Chain, Map},
+    ops::Range,
+};
+
+/// Two grids concatenated horizontally, padded with a [`Repeat`] grid.
+///
+/// See [`Grid::hcat_fill()`].
+pub type HCatFill<A, B> =
+    HCat<VCat<A, Repeat<<A as Grid>::Item>>, VCat<B, Repeat<<A as Grid>::Item>>>;
+
+/// Two grids concatenated vertically, padded with a [`Repeat`] grid.
+///
+/// See [`Grid::vcat_fill()`].
+pub type VCatFill<A, B> =
+    VCat<HCat<A, Repeat<<A as Grid>::Item>>, HCat<B, Repeat<<A as Grid>::Item>>>;
+
+type Iter<T> = <T as IntoIterator>::IntoIter;
+
+/// Splits `range` at `at`, returning the ranges before and after `at`
+/// (the latter being relative to `at`).
+fn split(Range { start, end }: Range<usize>, at: usize) -> (Range<usize>, Range<usize>) {
+    (
+        start.min(at)..end.min(at),
+        start.max(at) - at..end.max(at) - at,
+    )
+}
+
+macro_rules! cat {
+    ($(
+        $(#[$meta:meta])*
+        $Type:ident ($x:ident $y:ident)
+            $GridChained:ident $Chained:ident $chained:ident
+            $GridEither:ident $Either:ident $either:ident
+            $GridChaineds:ident $Chaineds:ident $chaineds:ident
+            $GridEithers:ident $Eithers:ident $eithers:ident
+    )*) => { $(
+        $(#[$meta])*
+        #[derive(Copy, Clone, Default, Debug)]
+        pub struct $Type<A, B> {
+            a:    A,
+            b:    B,
+            size: Size,
+        }
+
+        impl<A: WithSize, B: WithSize> $Type<A, B> {
+            pub(crate) fn new(a: A, b: B) -> Option<Self> {
+                let (size_a, size_b) = (a.size(), b.size());
+
+                if size_a.$y == size_b.$y {
+                    let mut size = size_a;
+                    size.$x += size_b.$x;
+
+                    Some(Self { a, b, size })
+                } else {
+                    None
+                }
+            }
+        }
+
+        impl<A, B> WithSize for $Type<A, B> {
+            fn size(&self) -> Size {
+                self.size
+            }
+        }
+
+        impl<A: Grid, B: Grid<Item = A::Item>> Grid for $Type<A, B> {
+            type Item = A::Item;
+
+            unsafe fn item_unchecked(self, index: impl Index0D) -> Self::Item {
+                let mut index = index.unchecked();
+                let at = self.a.size().$x;
+
+                if index.$x < at {
+                    self.a.item_unchecked(index)
+                } else {
+                    index.$x -= at;
+                    self.b.item_unchecked(index)
+                }
+            }
+        }
+
+        impl<A, B> $GridChained for $Type<A, B>
+        where
+            A: $GridChained,
+            B: $GridChained<Item = A::Item>,
+        {
+            type $Chained = Chain<Iter<A::$Chained>, Iter<B::$Chained>>;
+
+            unsafe fn $chained(self, index: impl Index1D) -> Self::$Chained {
+                let (i, range) = index.$chained(self.size);
+                let (range_a, range_b) = split(range, self.a.size().$x);
+
+                self.a
+                    .$chained((i, range_a))
+                    .into_iter()
+                    .chain(self.b.$chained((i, range_b)))
+            }
+        }
+
+        impl<A, B> $GridEither for $Type<A, B>
+        where
+            A: $GridEither,
+            B: $GridEither<Item = A::Item>,
+        {
+            type $Either = Either<Iter<A::$Either>, Iter<B::$Either>>;
+
+            unsafe fn $either(self, index: impl Index1D) -> Self::$Either {
+                let (i, range) = index.$either(self.size);
+                let at = self.a.size().$x;
+
+                if i < at {
+                    Either::A(self.a.$either((i, range)).into_iter())
+                } else {
+                    Either::B(self.b.$either((i - at, range)).into_iter())
+                }
+            }
+        }
+
+        impl<A, B> $GridChaineds for $Type<A, B>
+        where
+            A: $GridChaineds,
+            B: $GridChaineds<Item = A::Item>,
+        {
+            type $Chaineds = Map<
+                std::iter::Zip<Iter<A::$Chaineds>, Iter<B::$Chaineds>>,
+                fn((A::$Chained, B::$Chained)) -> Self::$Chained,
+            >;
+
+            unsafe fn $chaineds(self, index: impl Index2D) -> Self::$Chaineds {
+                let index = index.unchecked(self.size);
+                let (range_a, range_b) = split(index.$x.clone(), self.a.size().$x);
+                let (mut index_a, mut index_b) = (index.clone(), index);
+                index_a.$x = range_a;
+                index_b.$x = range_b;
+
+                self.a
+                    .$chaineds(index_a)
+                    .into_iter()
+                    .zip(self.b.$chaineds(index_b))
+                    .map(|(a, b)| a.into_iter().chain(b))
+            }
+        }
+
+        impl<A, B> $GridEithers for $Type<A, B>
+        where
+            A: $GridEithers,
+            B: $GridEithers<Item = A::Item>,
+        {
+            type $Eithers = Chain<
+                Map<Iter<A::$Eithers>, fn(A::$Either) -> Self::$Either>,
+                Map<Iter<B::$Eithers>, fn(B::$Either) -> Self::$Either>,
+            >;
+
+            unsafe fn $eithers(self, index: impl Index2D) -> Self::$Eithers {
+                let index = index.unchecked(self.size);
+                let (range_a, range_b) = split(index.$x.clone(), self.a.size().$x);
+                let (mut index_a, mut index_b) = (index.clone(), index);
+                index_a.$x = range_a;
+                index_b.$x = range_b;
+
+                self.a
+                    .$eithers(index_a)
+                    .into_iter()
+                    .map((|a: A::$Either| Either::A(a.into_iter())) as fn(_) -> _)
+                    .chain(
+                        self.b
+                            .$eithers(index_b)
+                            .into_iter()
+                            .map((|b: B::$Either| Either::B(b.into_iter())) as fn(_) -> _),
+                    )
+            }
+        }
+
+        impl<A, B> GridItems for $Type<A, B>
+        where
+            A: GridItems,
+            B: GridItems<Item = A::Item>,
+        {
+            type Items = Chain<Iter<A::Items>, Iter<B::Items>>;
+
+            unsafe fn items_unchecked(self, index: impl Index2D) -> Self::Items {
+                let index = index.unchecked(self.size);
+                let (range_a, range_b) = split(index.$x.clone(), self.a.size().$x);
+                let (mut index_a, mut index_b) = (index.clone(), index);
+                index_a.$x = range_a;
+                index_b.$x = range_b;
+
+                self.a
+                    .items_unchecked(index_a)
+                    .into_iter()
+                    .chain(self.b.items_unchecked(index_b))
+            }
+        }
+    )* };
+}
+
+cat!(
+    /// Two grids of the same height concatenated horizontally.
+    ///
+    /// Rows cross the seam from the first grid to the second grid.
+    ///
+    /// See [`Grid::hcat()`], [`Grid::hcat_fill()`].
+    HCat (x y)
+        GridRow Row row_unchecked
+        GridCol Col col_unchecked
+        GridRows Rows rows_unchecked
+        GridCols Cols cols_unchecked
+    /// Two grids of the same width concatenated vertically.
+    ///
+    /// Columns cross the seam from the first grid to the second grid.
+    ///
+    /// See [`Grid::vcat()`], [`Grid::vcat_fill()`].
+    VCat (y x)
+        GridCol Col col_unchecked
+        GridRow Row row_unchecked
+        GridCols Cols cols_unchecked
+        GridRows Rows rows_unchecked
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    fn line<'a>(line: impl IntoIterator<Item = &'a i32>) -> Vec<i32> {
+        line.into_iter().copied().collect()
+    }
+
+    fn lines<'a, L: IntoIterator<Item = impl IntoIterator<Item = &'a i32>>>(
+        lines: L,
+    ) -> Vec<Vec<i32>> {
+        lines.into_iter().map(line).collect()
+    }
+
+    fn grid(size: (usize, usize), items: Range<i32>) -> RowVec1D<i32> {
+        RowVec1D::new(size, items.collect()).unwrap()
+    }
+
+    #[test]
+    fn hcat() {
+        let (left, right) = (grid((2, 2), 1..5), grid((3, 2), 5..11));
+        let cat = (&left).hcat(&right).unwrap();
+
+        assert_eq!(cat.size(), Size { x: 5, y: 2 });
+        let rows = lines(cat.rows(..).unwrap());
+        assert_eq!(rows, [[1, 2, 5, 6, 7], [3, 4, 8, 9, 10]]);
+        assert_eq!(line(cat.row((0, 1..4)).unwrap()), [2, 5, 6]);
+        assert_eq!(line(cat.row((1, 3..5)).unwrap()), [9, 10]);
+        assert_eq!(lines(cat.rows((1..3, ..)).unwrap()), [[2, 5], [4, 8]]);
+        assert_eq!(lines(cat.rows((2..5, 1..2)).unwrap()), [[8, 9, 10]]);
+        assert_eq!(line(cat.col(3).unwrap()), [6, 9]);
+        assert_eq!(lines(cat.cols((1..4, 0..1)).unwrap()), [[2], [5], [6]]);
+        assert_eq!(cat.item((4, 1)), Some(&10));
+
+        assert!((&left).hcat(&grid((3, 1), 0..3)).is_none());
+    }
+
+    #[test]
+    fn vcat() {
+        let (top, bottom) = (grid((2, 1), 1..3), grid((2, 2), 3..7));
+        let cat = (&top).vcat(&bottom).unwrap();
+
+        assert_eq!(cat.size(), Size { x: 2, y: 3 });
+        assert_eq!(lines(cat.cols(..).unwrap()), [[1, 3, 5], [2, 4, 6]]);
+        assert_eq!(line(cat.col((1, 0..2)).unwrap()), [2, 4]);
+        assert_eq!(line(cat.col((0, 1..3)).unwrap()), [3, 5]);
+        assert_eq!(line(cat.row(1).unwrap()), [3, 4]);
+        assert_eq!(cat.item((1, 2)), Some(&6));
+
+        assert!((&top).vcat(&grid((3, 1), 0..3)).is_none());
+    }
+
+    #[test]
+    fn hcat_fill() {
+        let (left, right) = (grid((1, 2), 1..3), grid((2, 3), 3..9));
+        let cat = (&left).hcat_fill(&right, &0);
+
+        assert_eq!(cat.size(), Size { x: 3, y: 3 });
+        let rows = lines(cat.rows(..).unwrap());
+        assert_eq!(rows, [[1, 3, 4], [2, 5, 6], [0, 7, 8]]);
+        assert_eq!(line(cat.row((2, 0..2)).unwrap()), [0, 7]);
+        assert_eq!(line(cat.row((2, 1..3)).unwrap()), [7, 8]);
+        assert_eq!(line(cat.col(0).unwrap()), [1, 2, 0]);
+    }
+
+    #[test]
+    fn vcat_fill() {
+        let (top, bottom) = (grid((2, 1), 1..3), grid((3, 2), 3..9));
+        let cat = (&top).vcat_fill(&bottom, &0);
+
+        assert_eq!(cat.size(), Size { x: 3, y: 3 });
+        let rows = lines(cat.rows(..).unwrap());
+        assert_eq!(rows, [[1, 2, 0], [3, 4, 5], [6, 7, 8]]);
+        assert_eq!(line(cat.col((2, 0..2)).unwrap()), [0, 5]);
+        assert_eq!(line(cat.col((2, 1..3)).unwrap()), [5, 8]);
+        assert_eq!(lines(cat.cols((1..3, ..)).unwrap()), [[2, 4, 7], [0, 5, 8]]);
+    }
+}
@@ -18,6 +18,12 @@ impl<T: WithSize> Crop<T> {
         Some(Self { rect, grid })
     }
 
+    pub(crate) fn try_new(rect: impl Index2D, grid: T) -> Result<Self, GridError> {
+        let rect = rect.try_checked(grid.size())?;
+
+        Ok(Self { rect, grid })
+    }
+
     pub(crate) unsafe fn new_unchecked(rect: impl Index2D, grid: T) -> Self {
         let rect = rect.unchecked(grid.size());
 
@@ -0,0 +1,219 @@
+//! Differences between grids.
+
+use crate::grid::*;
+use std::{iter::Zip, ops::Range};
+
+type RowsIter<T> = <<T as GridRows>::Rows as IntoIterator>::IntoIter;
+type RowIter<T> = <<T as GridRow>::Row as IntoIterator>::IntoIter;
+
+/// Creates an iterator over the spans where `a` and `b` differ, or returns
+/// [`None`] if their sizes differ.
+///
+/// Spans are yielded row by row, as `(y, x_start..x_end)` (a valid
+/// [`Index1D`] for rows). Adjacent differing items are yielded as a single
+/// span. See [`Diff::rects()`] to merge spans into [`Rect`]s.
+///
+/// # Example
+///
+/// ```
+/// # use tender::grid::*;
+/// # use tender::geometry::*;
+/// let a = RowVec1D::new((4, 3), vec![0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0]).unwrap();
+/// let b = RowVec1D::new((4, 3), vec![0, 1, 1, 0, 0, 1, 1, 0, 1, 0, 0, 1]).unwrap();
+///
+/// let spans = diff(&a, &b).unwrap().collect::<Vec<_>>();
+/// assert_eq!(spans, [(0, 1..3), (1, 1..3), (2, 0..1), (2, 3..4)]);
+///
+/// let rects = diff(&a, &b).unwrap().rects();
+/// assert_eq!(rects, [
+///     Rect { x: 1..3, y: 0..2 },
+///     Rect { x: 0..1, y: 2..3 },
+///     Rect { x: 3..4, y: 2..3 },
+/// ]);
+/// ```
+pub fn diff<A, B>(a: A, b: B) -> Option<Diff<A, B>>
+where
+    A: GridRows,
+    B: GridRows,
+    A::Item: PartialEq<B::Item>,
+{
+    if a.size() != b.size() {
+        return None;
+    }
+
+    // SAFETY: sizes match, so both ranges are in bounds
+    let rows = unsafe { a.rows_unchecked(..).into_iter().zip(b.rows_unchecked(..)) };
+
+    Some(Diff {
+        rows,
+        row: None,
+        y: 0,
+        x: 0,
+    })
+}
+
+/// An iterator over the spans where two grids differ.
+///
+/// See [`diff()`].
+pub struct Diff<A: GridRows, B: GridRows> {
+    rows: Zip<RowsIter<A>, RowsIter<B>>,
+    row:  Option<Zip<RowIter<A>, RowIter<B>>>,
+    y:    usize,
+    x:    usize,
+}
+
+impl<A, B> Diff<A, B>
+where
+    A: GridRows,
+    B: GridRows,
+    A::Item: PartialEq<B::Item>,
+{
+    /// Merges the remaining spans into [`Rect`]s.
+    ///
+    /// Spans covering the same columns in consecutive rows are merged
+    /// together. The [`Rect`]s are sorted by their top row, then their left
+    /// column, and do not overlap.
+    pub fn rects(self) -> Vec<Rect> {
+        let mut rects = Vec::<Rect>::new();
+        // Rects ending on the previous row, by column
+        let mut open = Vec::<usize>::new();
+        let mut next = Vec::<usize>::new();
+        let mut row = 0;
+
+        for (y, x) in self {
+            if y != row {
+                open = if y == row + 1 {
+                    std::mem::take(&mut next)
+                } else {
+                    next.clear();
+                    Vec::new()
+                };
+                row = y;
+            }
+
+            let merged = open
+                .iter()
+                .position(|&i| rects[i].x == x)
+                .map(|position| open.remove(position));
+
+            match merged {
+                Some(i) => {
+                    rects[i].y.end = y + 1;
+                    next.push(i);
+                }
+                None => {
+                    next.push(rects.len());
+                    rects.push(Rect { x, y: y..y + 1 });
+                }
+            }
+        }
+
+        rects
+    }
+}
+
+impl<A, B> Iterator for Diff<A, B>
+where
+    A: GridRows,
+    B: GridRows,
+    A::Item: PartialEq<B::Item>,
+{
+    type Item = (usize, Range<usize>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(row) = &mut self.row {
+                // Find the start of the span
+                for (a, b) in &mut *row {
+                    self.x += 1;
+
+                    if a != b {
+                        let start = self.x - 1;
+
+                        // Find the end of the span
+                        for (a, b) in &mut *row {
+                            if a == b {
+                                let span = start..self.x;
+                                self.x += 1;
+
+                                return Some((self.y, span));
+                            }
+                            self.x += 1;
+                        }
+
+                        return Some((self.y, start..self.x));
+                    }
+                }
+
+                self.y += 1;
+            }
+
+            let (a, b) = self.rows.next()?;
+            self.row = Some(a.into_iter().zip(b));
+            self.x = 0;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    fn grid(items: &[u8]) -> RowVec1D<u8> {
+        RowVec1D::new((3, items.len() / 3), items.to_vec()).unwrap()
+    }
+
+    #[test]
+    fn spans_touching_row_ends() {
+        #[rustfmt::skip]
+        let b = grid(&[
+            1, 0, 1,
+            1, 1, 1,
+            0, 1, 1,
+        ]);
+        let spans = diff(&grid(&[0; 9]), &b).unwrap().collect::<Vec<_>>();
+
+        assert_eq!(spans, [(0, 0..1), (0, 2..3), (1, 0..3), (2, 1..3)]);
+    }
+
+    #[test]
+    fn equal_and_mismatched() {
+        let a = grid(&[1, 2, 3, 4, 5, 6]);
+
+        assert_eq!(diff(&a, &a).unwrap().count(), 0);
+        assert!(diff(&a, &grid(&[1, 2, 3])).is_none());
+    }
+
+    #[test]
+    fn rects_of_non_adjacent_rows() {
+        #[rustfmt::skip]
+        let b = grid(&[
+            0, 1, 1,
+            0, 0, 0,
+            0, 1, 1,
+            0, 1, 1,
+            1, 0, 0,
+        ]);
+        let rects = diff(&grid(&[0; 15]), &b).unwrap().rects();
+
+        assert_eq!(rects, [
+            Rect { x: 1..3, y: 0..1 },
+            Rect { x: 1..3, y: 2..4 },
+            Rect { x: 0..1, y: 4..5 },
+        ]);
+    }
+
+    #[test]
+    fn rects_with_shifted_spans() {
+        #[rustfmt::skip]
+        let b = grid(&[
+            1, 1, 0,
+            0, 1, 1,
+            0, 1, 1,
+        ]);
+        let rects = diff(&grid(&[0; 9]), &b).unwrap().rects();
+
+        assert_eq!(rects, [Rect { x: 0..2, y: 0..1 }, Rect { x: 1..3, y: 1..3 }]);
+    }
+}
@@ -0,0 +1,92 @@
+use crate::grid::*;
+use std::{
+    error::Error,
+    fmt::{self, Display, Formatter},
+    ops::Bound,
+};
+
+/// The x or y axis.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum Axis {
+    /// The x axis.
+    X,
+    /// The y axis.
+    Y,
+}
+
+impl Display for Axis {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Self::X => f.write_str("x"),
+            Self::Y => f.write_str("y"),
+        }
+    }
+}
+
+/// Errors of checked grid operations.
+///
+/// See the `try_*` methods of [`Index0D`], [`Index1D`], [`Index2D`],
+/// [`ToRange`], [`Grid`], [`Grid1D`], [`Grid2D`].
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+pub enum GridError {
+    /// `index` is not in `0..len` on `axis`.
+    OutOfBounds {
+        axis:  Axis,
+        index: usize,
+        len:   usize,
+    },
+    /// The range `(start, end)` is not in `0..len` on `axis`, or is
+    /// decreasing.
+    RangeOutOfBounds {
+        axis:  Axis,
+        start: Bound<usize>,
+        end:   Bound<usize>,
+        len:   usize,
+    },
+    /// A collection of `len` items does not fit in `size`.
+    LenMismatch { len: usize, size: Size },
+    /// The inner collection at `line` has `len` items instead of `expected`.
+    LineLenMismatch {
+        line:     usize,
+        len:      usize,
+        expected: usize,
+    },
+}
+
+impl Display for GridError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Self::OutOfBounds { axis, index, len } => write!(
+                f,
+                "index {} is out of bounds on {} axis (len is {})",
+                index, axis, len
+            ),
+            Self::RangeOutOfBounds {
+                axis,
+                start,
+                end,
+                len,
+            } => write!(
+                f,
+                "range ({:?}, {:?}) is out of bounds on {} axis (len is {})",
+                start, end, axis, len
+            ),
+            Self::LenMismatch { len, size } => write!(
+                f,
+                "{} items do not fit in a {}x{} grid",
+                len, size.x, size.y
+            ),
+            Self::LineLenMismatch {
+                line,
+                len,
+                expected,
+            } => write!(
+                f,
+                "line {} has {} items instead of {}",
+                line, len, expected
+            ),
+        }
+    }
+}
+
+impl Error for GridError {}
@@ -0,0 +1,244 @@
+//! Grids that flip or rotate grids.
+
+use crate::grid::*;
+use std::{
+    iter::{Map, Rev},
+    ops::Range,
+};
+
+/// A grid rotated by 90 degrees clockwise.
+///
+/// See [`Grid::rotate90()`].
+pub type Rotate90<T> = FlipX<Transpose<T>>;
+
+/// A grid rotated by 180 degrees.
+///
+/// See [`Grid::rotate180()`].
+pub type Rotate180<T> = FlipY<FlipX<T>>;
+
+/// A grid rotated by 270 degrees clockwise (90 degrees counterclockwise).
+///
+/// See [`Grid::rotate270()`].
+pub type Rotate270<T> = FlipY<Transpose<T>>;
+
+type Iter<T> = <T as IntoIterator>::IntoIter;
+
+fn flip(i: usize, len: usize) -> usize {
+    len - 1 - i
+}
+
+fn flip_range(Range { start, end }: Range<usize>, len: usize) -> Range<usize> {
+    len - end..len - start
+}
+
+macro_rules! flip {
+    ($(
+        $(#[$meta:meta])*
+        $Type:ident ($x:ident)
+            $GridFlipped:ident $Flipped:ident $flipped:ident
+            $GridMoved:ident $Moved:ident $moved:ident
+            $GridFlippeds:ident $Flippeds:ident $flippeds:ident
+            $GridMoveds:ident $Moveds:ident $moveds:ident
+    )*) => { $(
+        $(#[$meta])*
+        #[derive(Copy, Clone, Default, Debug)]
+        pub struct $Type<T> {
+            grid: T,
+        }
+
+        impl<T> $Type<T> {
+            pub(crate) fn new(grid: T) -> Self {
+                Self { grid }
+            }
+
+            /// Returns the underlying grid.
+            pub fn into_inner(self) -> T {
+                self.grid
+            }
+        }
+
+        impl<T: WithSize> WithSize for $Type<T> {
+            fn size(&self) -> Size {
+                self.grid.size()
+            }
+        }
+
+        impl<T: Grid> Grid for $Type<T> {
+            type Item = T::Item;
+
+            unsafe fn item_unchecked(self, index: impl Index0D) -> Self::Item {
+                let mut index = index.unchecked();
+                index.$x = flip(index.$x, self.size().$x);
+
+                self.grid.item_unchecked(index)
+            }
+        }
+
+        impl<T: $GridFlipped> $GridFlipped for $Type<T>
+        where
+            Iter<T::$Flipped>: DoubleEndedIterator,
+        {
+            type $Flipped = Rev<Iter<T::$Flipped>>;
+
+            unsafe fn $flipped(self, index: impl Index1D) -> Self::$Flipped {
+                let (i, range) = index.$flipped(self.size());
+                let range = flip_range(range, self.size().$x);
+
+                self.grid.$flipped((i, range)).into_iter().rev()
+            }
+        }
+
+        impl<T: $GridMoved> $GridMoved for $Type<T> {
+            type $Moved = T::$Moved;
+
+            unsafe fn $moved(self, index: impl Index1D) -> Self::$Moved {
+                let (i, range) = index.$moved(self.size());
+                let i = flip(i, self.size().$x);
+
+                self.grid.$moved((i, range))
+            }
+        }
+
+        impl<T: $GridFlippeds> $GridFlippeds for $Type<T>
+        where
+            Iter<T::$Flipped>: DoubleEndedIterator,
+        {
+            type $Flippeds = Map<
+                Iter<T::$Flippeds>,
+                fn(T::$Flipped) -> Rev<Iter<T::$Flipped>>,
+            >;
+
+            unsafe fn $flippeds(self, index: impl Index2D) -> Self::$Flippeds {
+                let mut index = index.unchecked(self.size());
+                index.$x = flip_range(index.$x, self.size().$x);
+
+                self.grid
+                    .$flippeds(index)
+                    .into_iter()
+                    .map(|flipped| flipped.into_iter().rev())
+            }
+        }
+
+        impl<T: $GridMoveds> $GridMoveds for $Type<T>
+        where
+            Iter<T::$Moveds>: DoubleEndedIterator,
+        {
+            type $Moveds = Rev<Iter<T::$Moveds>>;
+
+            unsafe fn $moveds(self, index: impl Index2D) -> Self::$Moveds {
+                let mut index = index.unchecked(self.size());
+                index.$x = flip_range(index.$x, self.size().$x);
+
+                self.grid.$moveds(index).into_iter().rev()
+            }
+        }
+
+        impl<T: GridItems> GridItems for $Type<T> {
+            type Items = T::Items;
+
+            unsafe fn items_unchecked(self, index: impl Index2D) -> Self::Items {
+                let mut index = index.unchecked(self.size());
+                index.$x = flip_range(index.$x, self.size().$x);
+
+                self.grid.items_unchecked(index)
+            }
+        }
+    )* };
+}
+
+flip!(
+    /// A grid flipped along the x axis (mirrored horizontally).
+    ///
+    /// Rows are yielded right to left, columns are yielded in reverse order.
+    ///
+    /// See [`Grid::flip_x()`].
+    FlipX (x)
+        GridRow Row row_unchecked
+        GridCol Col col_unchecked
+        GridRows Rows rows_unchecked
+        GridCols Cols cols_unchecked
+    /// A grid flipped along the y axis (mirrored vertically).
+    ///
+    /// Columns are yielded bottom to top, rows are yielded in reverse order.
+    ///
+    /// See [`Grid::flip_y()`].
+    FlipY (y)
+        GridCol Col col_unchecked
+        GridRow Row row_unchecked
+        GridCols Cols cols_unchecked
+        GridRows Rows rows_unchecked
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    fn rows<'a, T>(grid: T) -> Vec<Vec<i32>>
+    where
+        T: GridRows<Item = &'a i32>,
+    {
+        grid.rows(..)
+            .unwrap()
+            .into_iter()
+            .map(|row| row.into_iter().copied().collect())
+            .collect()
+    }
+
+    fn row<'a>(row: impl IntoIterator<Item = &'a i32>) -> Vec<i32> {
+        row.into_iter().copied().collect()
+    }
+
+    fn grid1d() -> RowVec1D<i32> {
+        RowVec1D::new((3, 2), vec![1, 2, 3, 4, 5, 6]).unwrap()
+    }
+
+    fn grid2d() -> RowVec2D<i32> {
+        RowVec2D::new((3, 2), vec![vec![1, 2, 3], vec![4, 5, 6]]).unwrap()
+    }
+
+    #[test]
+    fn flips() {
+        let grid = grid1d();
+
+        assert_eq!(rows((&grid).flip_x()), [[3, 2, 1], [6, 5, 4]]);
+        assert_eq!(rows((&grid).flip_y()), [[4, 5, 6], [1, 2, 3]]);
+        assert_eq!((&grid).flip_x().item((0, 1)), Some(&6));
+        assert_eq!((&grid).flip_y().item((0, 1)), Some(&1));
+        assert_eq!(row((&grid).flip_x().row((0, 0..2)).unwrap()), [3, 2]);
+        assert_eq!(row((&grid).flip_x().row((1, 1..3)).unwrap()), [5, 4]);
+        assert_eq!(row((&grid).flip_y().row((0, 1..3)).unwrap()), [5, 6]);
+        assert_eq!(row((&grid).flip_x().col((0, 1..2)).unwrap()), [6]);
+    }
+
+    #[test]
+    fn rotations() {
+        let grid = grid1d();
+
+        assert_eq!((&grid).rotate90().size(), Size { x: 2, y: 3 });
+        assert_eq!(rows((&grid).rotate90()), [[4, 1], [5, 2], [6, 3]]);
+        assert_eq!(rows((&grid).rotate180()), [[6, 5, 4], [3, 2, 1]]);
+        assert_eq!(rows((&grid).rotate270()), [[3, 6], [2, 5], [1, 4]]);
+        assert_eq!((&grid).rotate90().item((0, 2)), Some(&6));
+        assert_eq!(row((&grid).rotate90().row((1, 1..2)).unwrap()), [2]);
+        assert_eq!(row((&grid).rotate270().row((2, 0..1)).unwrap()), [1]);
+    }
+
+    #[test]
+    fn reversed_rows() {
+        let (grid1d, grid2d) = (grid1d(), grid2d());
+
+        for flipped in [rows((&grid1d).flip_x()), rows((&grid2d).flip_x())] {
+            assert_eq!(flipped, [[3, 2, 1], [6, 5, 4]]);
+        }
+
+        let rev = (&grid1d).flip_x().rows(..).unwrap().rev().map(row);
+        assert_eq!(rev.collect::<Vec<_>>(), [[6, 5, 4], [3, 2, 1]]);
+
+        let rev = (&grid2d).flip_x().rows((0..2, ..)).unwrap().rev().map(row);
+        assert_eq!(rev.collect::<Vec<_>>(), [[6, 5], [3, 2]]);
+
+        let row = (&grid2d).flip_y().row((0, 1..3)).unwrap();
+        assert_eq!(row.iter().rev().copied().collect::<Vec<_>>(), [6, 5]);
+    }
+}
@@ -0,0 +1,230 @@
+use crate::grid::*;
+use std::convert::TryInto;
+
+/// Conversion from a grid.
+///
+/// Row-major grids collect [`GridRows`], column-major grids collect
+/// [`GridCols`], so that items are never reordered. Arrays are collected into
+/// an [`Option`], which is [`None`] if the size does not match.
+///
+/// ### Panics
+///
+/// Vec grids panic if `grid` does not yield `x * y` items, i.e. if its
+/// [`GridRows`]/[`GridCols`] implementation disagrees with its size. Arrays
+/// are [`None`] instead.
+///
+/// See [`Grid::collect_grid()`].
+///
+/// # Example
+///
+/// ```
+/// # use tender::grid::*;
+/// let grid = RowVec1D::new((2, 2), vec![1, 2, 3, 4]).unwrap();
+///
+/// let vec: RowVec1D<i32> = (&grid).map(|i| i * 10).collect_grid();
+/// assert_eq!(vec.as_ref(), &[10, 20, 30, 40]);
+///
+/// let cols: ColVec2D<&i32> = (&grid).collect_grid();
+/// assert_eq!(cols.as_ref(), &[vec![&1, &3], vec![&2, &4]]);
+///
+/// let array: Option<RowArray1D<&i32, 4>> = (&grid).collect_grid();
+/// assert!(array.is_some());
+/// ```
+pub trait FromGrid<T>: Sized {
+    /// Creates a value from a grid.
+    fn from_grid(grid: T) -> Self;
+}
+
+const MISMATCH: &str = "Grid yields a different number of items than its size";
+
+macro_rules! from_grid {
+    ($($M:ident $Trait:ident $lines:ident)*) => { $(
+        impl<T: $Trait> FromGrid<T> for Vec1D<$M, T::Item> {
+            fn from_grid(grid: T) -> Self {
+                let size = grid.size();
+                // SAFETY: .. is in bounds
+                let items = unsafe { grid.$lines(..) }
+                    .into_iter()
+                    .flatten()
+                    .collect();
+
+                Grid1D::new(size, items).expect(MISMATCH)
+            }
+        }
+
+        impl<T: $Trait> FromGrid<T> for Vec2D<$M, T::Item> {
+            fn from_grid(grid: T) -> Self {
+                let size = grid.size();
+                // SAFETY: .. is in bounds
+                let items = unsafe { grid.$lines(..) }
+                    .into_iter()
+                    .map(|line| line.into_iter().collect())
+                    .collect();
+
+                Grid2D::new(size, items).expect(MISMATCH)
+            }
+        }
+
+        impl<T: $Trait, const L: usize> FromGrid<T> for Option<Array1D<$M, T::Item, L>> {
+            fn from_grid(grid: T) -> Self {
+                let size = grid.size();
+                // SAFETY: .. is in bounds
+                let items = unsafe { grid.$lines(..) }
+                    .into_iter()
+                    .flatten()
+                    .collect::<Vec<_>>()
+                    .try_into()
+                    .ok()?;
+
+                Grid1D::new(size, items)
+            }
+        }
+
+        impl<T: $Trait, const MAJ: usize, const MIN: usize> FromGrid<T>
+            for Option<Array2D<$M, T::Item, MAJ, MIN>>
+        {
+            fn from_grid(grid: T) -> Self {
+                let size = grid.size();
+                // SAFETY: .. is in bounds
+                let items = unsafe { grid.$lines(..) }
+                    .into_iter()
+                    .map(|line| line.into_iter().collect::<Vec<_>>().try_into().ok())
+                    .collect::<Option<Vec<[T::Item; MAJ]>>>()?
+                    .try_into()
+                    .ok()?;
+
+                Grid2D::new(size, items)
+            }
+        }
+    )* };
+}
+
+from_grid!(
+    RowMajor GridRows rows_unchecked
+    ColMajor GridCols cols_unchecked
+);
+
+impl<I> Vec1D<RowMajor, I> {
+    /// Creates a new [`RowVec1D`] from rows, or [`None`] if their lengths
+    /// differ.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use tender::grid::*;
+    /// let grid = RowVec1D::from_rows(vec![1..3, 3..5]).unwrap();
+    /// assert_eq!(grid.as_ref(), &[1, 2, 3, 4]);
+    ///
+    /// assert!(RowVec1D::from_rows(vec![1..3, 3..6]).is_none());
+    /// ```
+    pub fn from_rows<R: IntoIterator<Item = I>>(rows: impl IntoIterator<Item = R>) -> Option<Self> {
+        Self::try_from_rows(rows).ok()
+    }
+
+    /// Creates a new [`RowVec1D`] from rows, or a
+    /// [`GridError::LineLenMismatch`] if their lengths differ.
+    pub fn try_from_rows<R: IntoIterator<Item = I>>(
+        rows: impl IntoIterator<Item = R>,
+    ) -> Result<Self, GridError> {
+        let mut items = Vec::new();
+        let mut width = None;
+        let mut height = 0;
+
+        for (line, row) in rows.into_iter().enumerate() {
+            let start = items.len();
+            items.extend(row);
+
+            let len = items.len() - start;
+            let expected = *width.get_or_insert(len);
+            if len != expected {
+                return Err(GridError::LineLenMismatch {
+                    line,
+                    len,
+                    expected,
+                });
+            }
+
+            height += 1;
+        }
+
+        // SAFETY: there are width * height items
+        Ok(unsafe { Grid1D::new_unchecked((width.unwrap_or(0), height), items) })
+    }
+}
+
+impl<I> Vec2D<RowMajor, I> {
+    /// Creates a new [`RowVec2D`] from rows, or [`None`] if their lengths
+    /// differ.
+    pub fn from_rows<R: IntoIterator<Item = I>>(rows: impl IntoIterator<Item = R>) -> Option<Self> {
+        Self::try_from_rows(rows).ok()
+    }
+
+    /// Creates a new [`RowVec2D`] from rows, or a
+    /// [`GridError::LineLenMismatch`] if their lengths differ.
+    pub fn try_from_rows<R: IntoIterator<Item = I>>(
+        rows: impl IntoIterator<Item = R>,
+    ) -> Result<Self, GridError> {
+        let rows = rows
+            .into_iter()
+            .map(|row| row.into_iter().collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+        let width = rows.first().map_or(0, Vec::len);
+
+        Grid2D::try_new((width, rows.len()), rows)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{
+        iter::{Empty, RepeatN},
+        panic::catch_unwind,
+    };
+
+    /// A grid of `size` whose rows are all empty.
+    #[derive(Copy, Clone)]
+    struct Short(Size);
+
+    impl WithSize for Short {
+        fn size(&self) -> Size {
+            self.0
+        }
+    }
+
+    impl Grid for Short {
+        type Item = u8;
+
+        unsafe fn item_unchecked(self, _: impl Index0D) -> u8 {
+            0
+        }
+    }
+
+    impl GridRow for Short {
+        type Row = Empty<u8>;
+
+        unsafe fn row_unchecked(self, _: impl Index1D) -> Self::Row {
+            std::iter::empty()
+        }
+    }
+
+    impl GridRows for Short {
+        type Rows = RepeatN<Empty<u8>>;
+
+        unsafe fn rows_unchecked(self, _: impl Index2D) -> Self::Rows {
+            std::iter::repeat_n(std::iter::empty(), self.0.y)
+        }
+    }
+
+    #[test]
+    fn short_rows() {
+        let short = Short(Size { x: 2, y: 2 });
+
+        assert!(catch_unwind(|| RowVec1D::<u8>::from_grid(short)).is_err());
+        assert!(catch_unwind(|| RowVec2D::<u8>::from_grid(short)).is_err());
+        assert!(Option::<RowArray1D<u8, 4>>::from_grid(short).is_none());
+        assert!(Option::<RowArray1D<u8, 0>>::from_grid(short).is_none());
+        assert!(Option::<RowArray2D<u8, 2, 2>>::from_grid(short).is_none());
+        assert!(Option::<RowArray2D<u8, 0, 2>>::from_grid(short).is_none());
+    }
+}
@@ -27,6 +27,14 @@ pub trait Grid: WithSize + Sized {
         Some(unsafe { self.item_unchecked(index) })
     }
 
+    /// Returns the item at `index`, or a [`GridError`] if out of bounds.
+    fn try_item(self, index: impl Index0D) -> Result<Self::Item, GridError> {
+        let index = index.try_checked(self.size())?;
+
+        // SAFETY: index is checked
+        Ok(unsafe { self.item_unchecked(index) })
+    }
+
     /// Creates a grid which copies all of its elements.
     ///
     /// This is useful when you have a grid over `&T`, but you need a
@@ -68,14 +76,128 @@ pub trait Grid: WithSize + Sized {
         Crop::new(rect, self)
     }
 
+    /// Creates a new grid by cropping with `rect`, or a [`GridError`] if out
+    /// of bounds.
+    fn try_crop(self, rect: impl Index2D) -> Result<Crop<Self>, GridError> {
+        Crop::try_new(rect, self)
+    }
+
+    /// Creates a new grid by swapping the x and y axis.
+    ///
+    /// Columns become rows and rows become columns.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use tender::grid::*;
+    /// let grid = ColVec1D::new((3, 2), vec![1, 2, 3, 4, 5, 6]).unwrap();
+    /// let mut rows = grid.transpose().rows(..).unwrap();
+    ///
+    /// assert_eq!(rows.next(), Some(&[1, 2][..]));
+    /// assert_eq!(rows.next(), Some(&[3, 4][..]));
+    /// assert_eq!(rows.next(), Some(&[5, 6][..]));
+    /// assert_eq!(rows.next(), None);
+    /// ```
+    fn transpose(self) -> Transpose<Self> {
+        Transpose::new(self)
+    }
+
+    /// Creates a new grid by flipping along the x axis.
+    ///
+    /// The grid is mirrored horizontally: rows are yielded right to left.
+    fn flip_x(self) -> FlipX<Self> {
+        FlipX::new(self)
+    }
+
+    /// Creates a new grid by flipping along the y axis.
+    ///
+    /// The grid is mirrored vertically: columns are yielded bottom to top.
+    fn flip_y(self) -> FlipY<Self> {
+        FlipY::new(self)
+    }
+
+    /// Creates a new grid by rotating 90 degrees clockwise.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use tender::grid::*;
+    /// let grid = RowVec1D::new((3, 2), vec![1, 2, 3, 4, 5, 6]).unwrap();
+    /// let mut rows = grid.rotate90().rows(..).unwrap();
+    ///
+    /// assert!(rows.next().unwrap().eq(&[4, 1]));
+    /// assert!(rows.next().unwrap().eq(&[5, 2]));
+    /// assert!(rows.next().unwrap().eq(&[6, 3]));
+    /// assert!(rows.next().is_none());
+    /// ```
+    fn rotate90(self) -> Rotate90<Self> {
+        self.transpose().flip_x()
+    }
+
+    /// Creates a new grid by rotating 180 degrees.
+    fn rotate180(self) -> Rotate180<Self> {
+        self.flip_x().flip_y()
+    }
+
+    /// Creates a new grid by rotating 270 degrees clockwise (i.e. 90 degrees
+    /// counterclockwise).
+    fn rotate270(self) -> Rotate270<Self> {
+        self.transpose().flip_y()
+    }
+
     /// Creates a new grid by applying the provided function on each elements.
-    fn map<I>(self, f: fn(Self::Item) -> I) -> Map<Self, I> {
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use tender::grid::*;
+    /// let offset = 10;
+    /// let grid = RowVec1D::new((2, 2), vec![1, 2, 3, 4]).unwrap();
+    /// let mut rows = grid.map(|item| item + offset).rows(..).unwrap();
+    ///
+    /// assert!(rows.next().unwrap().eq(vec![11, 12]));
+    /// assert!(rows.next().unwrap().eq(vec![13, 14]));
+    /// assert!(rows.next().is_none());
+    /// ```
+    fn map<F: Fn(Self::Item) -> I, I>(self, f: F) -> Map<Self, F> {
         Map {
             grid: self,
             fun:  f,
         }
     }
 
+    /// Creates a new grid by applying the provided function on each elements
+    /// and their [`Point`].
+    fn map_with_point<F: Fn(Point, Self::Item) -> I, I>(self, f: F) -> MapWithPoint<Self, F> {
+        MapWithPoint {
+            grid: self,
+            fun:  f,
+        }
+    }
+
+    /// Creates a new grid which yields elements with their [`Point`].
+    ///
+    /// Points are relative to this grid: `grid.crop(rect).indexed()` starts
+    /// at `(0, 0)` while `grid.indexed().crop(rect)` starts at
+    /// `rect.start()`. Likewise, `grid.indexed().zip_at(position, other)`
+    /// yields points in `grid`'s coordinates.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use tender::geometry::*;
+    /// # use tender::grid::*;
+    /// let grid = RowVec1D::new((2, 2), vec!['a', 'b', 'c', 'd']).unwrap();
+    /// let mut items = grid.indexed().crop((1.., ..)).unwrap().flatten_rows();
+    ///
+    /// assert_eq!(items.next(), Some((Point { x: 1, y: 0 }, &'b')));
+    /// assert_eq!(items.next(), Some((Point { x: 1, y: 1 }, &'d')));
+    /// assert_eq!(items.next(), None);
+    /// ```
+    fn indexed(self) -> Indexed<Self> {
+        self.map_with_point((|point, item| (point, item)) as fn(_, _) -> _)
+    }
+
     /// ‘Zips up’ two grids into a single grid of pairs.
     ///
     /// Yields elements from the overlapping area of both grids.
@@ -85,11 +207,462 @@ pub trait Grid: WithSize + Sized {
 
     /// ‘Zips up’ two relatively positioned grids into a single grid of pairs.
     ///
-    /// Yields elements from the overlapping area of both grids.
-    fn zip_at<U: Grid>(self, position: impl Into<Point>, other: U) -> Zip<Crop<Self>, Crop<U>> {
+    /// Yields elements from the overlapping area of both grids. `position`
+    /// is signed: `other` may start above or left of `self`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use tender::grid::*;
+    /// let a = RowVec1D::new((2, 2), vec![1, 2, 3, 4]).unwrap();
+    /// let b = RowVec1D::new((2, 2), vec![5, 6, 7, 8]).unwrap();
+    /// let mut items = a.zip_at((-1, 1), &b).flatten_rows();
+    ///
+    /// assert_eq!(items.next(), Some((&3, &6)));
+    /// assert_eq!(items.next(), None);
+    /// ```
+    fn zip_at<U: Grid>(
+        self,
+        position: impl Into<Point<isize>>,
+        other: U,
+    ) -> Zip<Crop<Self>, Crop<U>> {
         Zip::at(self, other, position.into())
     }
 
+    /// ‘Zips up’ two grids into a single grid of optional pairs.
+    ///
+    /// Yields elements from the union area of both grids, with [`None`]
+    /// where a grid has no item.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use tender::grid::*;
+    /// let a = RowVec1D::new((2, 1), vec![1, 2]).unwrap();
+    /// let b = RowVec1D::new((1, 2), vec![3, 4]).unwrap();
+    /// let mut rows = a.zip_longest(&b).rows(..).unwrap();
+    ///
+    /// assert!(rows.next().unwrap().eq([(Some(&1), Some(&3)), (Some(&2), None)]));
+    /// assert!(rows.next().unwrap().eq([(None, Some(&4)), (None, None)]));
+    /// assert!(rows.next().is_none());
+    /// ```
+    fn zip_longest<U: Grid>(self, other: U) -> ZipLongest<Self, U> {
+        ZipLongest::new(self, other)
+    }
+
+    /// ‘Zips up’ two relatively positioned grids into a single grid of
+    /// optional pairs.
+    ///
+    /// `other` is at `position` relative to `self`, which can be negative.
+    /// Yields elements from the bounding area of both grids, starting at its
+    /// top left corner, with [`None`] where a grid has no item.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use tender::grid::*;
+    /// let a = RowVec1D::new((1, 1), vec![1]).unwrap();
+    /// let b = RowVec1D::new((1, 1), vec![2]).unwrap();
+    /// let mut rows = a.zip_longest_at((-1, 1), &b).rows(..).unwrap();
+    ///
+    /// assert!(rows.next().unwrap().eq([(None, None), (Some(&1), None)]));
+    /// assert!(rows.next().unwrap().eq([(None, Some(&2)), (None, None)]));
+    /// assert!(rows.next().is_none());
+    /// ```
+    fn zip_longest_at<U: Grid>(
+        self,
+        position: impl Into<Point<isize>>,
+        other: U,
+    ) -> ZipLongest<Self, U> {
+        ZipLongest::at(self, other, position.into())
+    }
+
+    /// Returns an iterator over all overlapping [`Crop`]s of `size`.
+    ///
+    /// Windows are yielded row by row. There are no windows if `size` is
+    /// larger than the grid.
+    ///
+    /// ### Panics
+    ///
+    /// Panics if `size.x` or `size.y` is 0.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use tender::grid::*;
+    /// let grid = RowVec1D::new((3, 2), vec![1, 2, 3, 4, 5, 6]).unwrap();
+    /// let sums = (&grid)
+    ///     .windows((2, 2))
+    ///     .map(|window| window.flatten_rows().sum())
+    ///     .collect::<Vec<i32>>();
+    ///
+    /// assert_eq!(sums, [12, 16]);
+    /// ```
+    fn windows(self, size: impl Into<Size>) -> Windows<Self>
+    where
+        Self: Clone,
+    {
+        Windows::new(self, size.into())
+    }
+
+    /// Returns an iterator over non-overlapping [`Crop`]s of `size`.
+    ///
+    /// Chunks are yielded row by row. Chunks on the right and bottom edges
+    /// are smaller when the grid's size is not a multiple of `size`.
+    ///
+    /// ### Panics
+    ///
+    /// Panics if `size.x` or `size.y` is 0.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use tender::geometry::*;
+    /// # use tender::grid::*;
+    /// let grid = RowVec1D::new((3, 3), vec![0; 9]).unwrap();
+    /// let sizes = (&grid)
+    ///     .chunks((2, 2))
+    ///     .map(|chunk| chunk.size())
+    ///     .collect::<Vec<_>>();
+    ///
+    /// assert_eq!(sizes, [
+    ///     Size { x: 2, y: 2 },
+    ///     Size { x: 1, y: 2 },
+    ///     Size { x: 2, y: 1 },
+    ///     Size { x: 1, y: 1 },
+    /// ]);
+    /// ```
+    fn chunks(self, size: impl Into<Size>) -> Chunks<Self>
+    where
+        Self: Clone,
+    {
+        Chunks::new(self, size.into())
+    }
+
+    /// Creates a new grid from a grid of equally sized tiles, or [`None`] if
+    /// tiles have different sizes.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use tender::grid::*;
+    /// let a = RowVec1D::new((2, 1), vec![1, 2]).unwrap();
+    /// let b = RowVec1D::new((2, 1), vec![3, 4]).unwrap();
+    /// let tiles = RowVec1D::new((2, 1), vec![a, b]).unwrap();
+    /// let mut rows = (&tiles).flatten_tiles().unwrap().rows(..).unwrap();
+    ///
+    /// assert!(rows.next().unwrap().eq(&[1, 2, 3, 4]));
+    /// assert!(rows.next().is_none());
+    /// ```
+    fn flatten_tiles(self) -> Option<Tiles<Self>>
+    where
+        Self: GridItems + Clone,
+        Self::Item: Grid,
+    {
+        Tiles::new(self)
+    }
+
+    /// Creates a new grid repeating each item `factor.x` times horizontally
+    /// and `factor.y` times vertically.
+    ///
+    /// ### Panics
+    ///
+    /// Panics if `factor.x` or `factor.y` is 0, or if the scaled size
+    /// overflows.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use tender::grid::*;
+    /// let grid = RowVec1D::new((2, 1), vec![1, 2]).unwrap();
+    /// let mut rows = (&grid).scale((2, 2)).rows(..).unwrap();
+    ///
+    /// assert!(rows.next().unwrap().eq(&[1, 1, 2, 2]));
+    /// assert!(rows.next().unwrap().eq(&[1, 1, 2, 2]));
+    /// assert!(rows.next().is_none());
+    /// ```
+    fn scale(self, factor: impl Into<Size>) -> Scale<Self> {
+        Scale::new(self, factor.into())
+    }
+
+    /// Creates a new grid reducing each block of `factor` items to a single
+    /// item with `reducer`.
+    ///
+    /// Blocks on the right and bottom edges are smaller when the grid's size
+    /// is not a multiple of `factor`. Sample blocks with e.g.
+    /// `|block| block.item((0, 0)).unwrap()`.
+    ///
+    /// ### Panics
+    ///
+    /// Panics if `factor.x` or `factor.y` is 0.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use tender::grid::*;
+    /// let grid = RowVec1D::new((3, 2), vec![1, 2, 3, 4, 5, 6]).unwrap();
+    /// let sums = (&grid).downscale((2, 2), |block| block.items(..).unwrap().sum::<i32>());
+    ///
+    /// assert!(sums.rows(..).unwrap().next().unwrap().eq([12, 9]));
+    /// ```
+    fn downscale<F: Fn(Crop<Self>) -> I, I>(
+        self,
+        factor: impl Into<Size>,
+        reducer: F,
+    ) -> Downscale<Self, F> {
+        Downscale::new(self, factor.into(), reducer)
+    }
+
+    /// Returns an iterator over the neighbors of `point` with their
+    /// [`Point`], or [`None`] if `point` is out of bounds.
+    ///
+    /// Neighbors are yielded row by row. `edge` tells what to yield for
+    /// neighbors outside of the grid.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use tender::grid::*;
+    /// let grid = RowVec1D::new((3, 1), vec![1, 2, 3]).unwrap();
+    /// let neighbors = |edge| {
+    ///     (&grid)
+    ///         .neighbors((0, 0), Neighborhood::VonNeumann, edge)
+    ///         .unwrap()
+    ///         .map(|(_, item)| *item)
+    ///         .collect::<Vec<_>>()
+    /// };
+    ///
+    /// assert_eq!(neighbors(Edge::Clamp), [1, 1, 2, 1]);
+    /// assert_eq!(neighbors(Edge::Wrap), [1, 3, 2, 1]);
+    /// assert_eq!(neighbors(Edge::Skip), [2]);
+    /// ```
+    fn neighbors(
+        self,
+        point: impl Index0D,
+        neighborhood: Neighborhood,
+        edge: Edge,
+    ) -> Option<Neighbors<Self>>
+    where
+        Self: Clone,
+    {
+        Neighbors::new(self, point, neighborhood, edge)
+    }
+
+    /// Returns an iterator over the items on the perimeter of `rect` with
+    /// their [`Point`], or [`None`] if `rect` is out of bounds.
+    ///
+    /// Items are yielded clockwise, starting at the top left corner.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use tender::grid::*;
+    /// let grid = RowVec1D::new((3, 3), vec![1, 2, 3, 4, 5, 6, 7, 8, 9]).unwrap();
+    /// let border = (&grid).border(..).unwrap().map(|(_, item)| *item);
+    ///
+    /// assert!(border.eq([1, 2, 3, 6, 9, 8, 7, 4]));
+    /// ```
+    fn border(self, rect: impl Index2D) -> Option<Traverse<Self, Border>>
+    where
+        Self: Clone,
+    {
+        let rect = rect.checked(self.size())?;
+
+        // SAFETY: the rect is within the grid
+        Some(unsafe { Traverse::new_unchecked(self, Border::new(rect)) })
+    }
+
+    /// Returns an iterator over the concentric rings of `rect`, from the
+    /// outermost to the innermost, or [`None`] if `rect` is out of bounds.
+    ///
+    /// Each ring is a [`border()`](Grid::border).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use tender::grid::*;
+    /// let grid = RowVec1D::new((3, 3), vec![1, 2, 3, 4, 5, 6, 7, 8, 9]).unwrap();
+    /// let mut rings = (&grid).rings(..).unwrap();
+    ///
+    /// assert_eq!(rings.next().unwrap().len(), 8);
+    /// assert!(rings.next().unwrap().map(|(_, item)| *item).eq([5]));
+    /// assert!(rings.next().is_none());
+    /// ```
+    fn rings(self, rect: impl Index2D) -> Option<Rings<Self>>
+    where
+        Self: Clone,
+    {
+        Rings::new(self, rect)
+    }
+
+    /// Returns an iterator over the diagonals of `rect`, or [`None`] if
+    /// `rect` is out of bounds.
+    ///
+    /// Diagonals go down right and are yielded from the bottom left corner to
+    /// the top right corner.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use tender::grid::*;
+    /// let grid = RowVec1D::new((3, 2), vec![1, 2, 3, 4, 5, 6]).unwrap();
+    /// let diagonals = (&grid)
+    ///     .diagonals(..)
+    ///     .unwrap()
+    ///     .map(|diagonal| diagonal.map(|(_, item)| *item).collect::<Vec<_>>())
+    ///     .collect::<Vec<_>>();
+    ///
+    /// assert_eq!(diagonals, [vec![4], vec![1, 5], vec![2, 6], vec![3]]);
+    /// ```
+    fn diagonals(self, rect: impl Index2D) -> Option<Diagonals<Self>>
+    where
+        Self: Clone,
+    {
+        Diagonals::new(self, rect, false)
+    }
+
+    /// Returns an iterator over the anti-diagonals of `rect`, or [`None`] if
+    /// `rect` is out of bounds.
+    ///
+    /// Anti-diagonals go down left and are yielded from the top left corner
+    /// to the bottom right corner.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use tender::grid::*;
+    /// let grid = RowVec1D::new((3, 2), vec![1, 2, 3, 4, 5, 6]).unwrap();
+    /// let diagonals = (&grid)
+    ///     .anti_diagonals(..)
+    ///     .unwrap()
+    ///     .map(|diagonal| diagonal.map(|(_, item)| *item).collect::<Vec<_>>())
+    ///     .collect::<Vec<_>>();
+    ///
+    /// assert_eq!(diagonals, [vec![1], vec![2, 4], vec![3, 5], vec![6]]);
+    /// ```
+    fn anti_diagonals(self, rect: impl Index2D) -> Option<Diagonals<Self>>
+    where
+        Self: Clone,
+    {
+        Diagonals::new(self, rect, true)
+    }
+
+    /// Returns an iterator over the items of `rect` with their [`Point`],
+    /// spiraling clockwise from the top left corner to the center, or
+    /// [`None`] if `rect` is out of bounds.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use tender::grid::*;
+    /// let grid = RowVec1D::new((3, 3), vec![1, 2, 3, 4, 5, 6, 7, 8, 9]).unwrap();
+    /// let spiral = (&grid).spiral(..).unwrap().map(|(_, item)| *item);
+    ///
+    /// assert!(spiral.eq([1, 2, 3, 6, 9, 8, 7, 4, 5]));
+    /// ```
+    fn spiral(self, rect: impl Index2D) -> Option<Traverse<Self, Spiral>>
+    where
+        Self: Clone,
+    {
+        let rect = rect.checked(self.size())?;
+
+        // SAFETY: the rect is within the grid
+        Some(unsafe { Traverse::new_unchecked(self, Spiral::new(rect)) })
+    }
+
+    /// Returns an iterator over the items of `rect` with their [`Point`] in
+    /// Z-order (Morton order), or [`None`] if `rect` is out of bounds or too
+    /// large for Morton codes to fit in a `usize`.
+    ///
+    /// Nearby points stay close in that order, which makes for cache-friendly
+    /// traversals. See [`Morton`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use tender::grid::*;
+    /// let grid = RowVec1D::new((3, 3), vec![1, 2, 3, 4, 5, 6, 7, 8, 9]).unwrap();
+    /// let morton = (&grid).morton(..).unwrap().map(|(_, item)| *item);
+    ///
+    /// assert!(morton.eq([1, 2, 4, 5, 3, 6, 7, 8, 9]));
+    /// ```
+    fn morton(self, rect: impl Index2D) -> Option<Traverse<Self, Morton>>
+    where
+        Self: Clone,
+    {
+        let morton = Morton::new(rect.checked(self.size())?)?;
+
+        // SAFETY: the rect is within the grid
+        Some(unsafe { Traverse::new_unchecked(self, morton) })
+    }
+
+    /// Concatenates two grids of the same height horizontally, or [`None`]
+    /// if heights differ.
+    ///
+    /// See [`hcat_fill()`](Grid::hcat_fill) to concatenate grids of
+    /// different heights.
+    fn hcat<U: Grid<Item = Self::Item>>(self, other: U) -> Option<HCat<Self, U>> {
+        HCat::new(self, other)
+    }
+
+    /// Concatenates two grids of the same width vertically, or [`None`] if
+    /// widths differ.
+    ///
+    /// See [`vcat_fill()`](Grid::vcat_fill) to concatenate grids of
+    /// different widths.
+    fn vcat<U: Grid<Item = Self::Item>>(self, other: U) -> Option<VCat<Self, U>> {
+        VCat::new(self, other)
+    }
+
+    /// Concatenates two grids horizontally, covering the gap below the
+    /// shortest grid with `fill`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use tender::grid::*;
+    /// let left = RowVec1D::new((1, 2), vec![1, 2]).unwrap();
+    /// let right = RowVec1D::new((2, 1), vec![3, 4]).unwrap();
+    /// let mut rows = left.hcat_fill(&right, &0).rows(..).unwrap();
+    ///
+    /// assert!(rows.next().unwrap().eq(&[1, 3, 4]));
+    /// assert!(rows.next().unwrap().eq(&[2, 0, 0]));
+    /// assert!(rows.next().is_none());
+    /// ```
+    fn hcat_fill<U: Grid<Item = Self::Item>>(self, other: U, fill: Self::Item) -> HCatFill<Self, U>
+    where
+        Self::Item: Clone,
+    {
+        let (size_a, size_b) = (self.size(), other.size());
+        let y = size_a.y.max(size_b.y);
+        let fill_a = repeat((size_a.x, y - size_a.y), fill.clone());
+        let fill_b = repeat((size_b.x, y - size_b.y), fill);
+
+        // Both grids are padded to the same height
+        let a = self.vcat(fill_a).expect("Fill has the width of self");
+        let b = other.vcat(fill_b).expect("Fill has the width of other");
+
+        a.hcat(b).expect("Padded grids have the same height")
+    }
+
+    /// Concatenates two grids vertically, covering the gap right of the
+    /// narrowest grid with `fill`.
+    fn vcat_fill<U: Grid<Item = Self::Item>>(self, other: U, fill: Self::Item) -> VCatFill<Self, U>
+    where
+        Self::Item: Clone,
+    {
+        let (size_a, size_b) = (self.size(), other.size());
+        let x = size_a.x.max(size_b.x);
+        let fill_a = repeat((x - size_a.x, size_a.y), fill.clone());
+        let fill_b = repeat((x - size_b.x, size_b.y), fill);
+
+        // Both grids are padded to the same width
+        let a = self.hcat(fill_a).expect("Fill has the height of self");
+        let b = other.hcat(fill_b).expect("Fill has the height of other");
+
+        a.vcat(b).expect("Padded grids have the same width")
+    }
+
     /// Flatten the columns of a grid.
     fn flatten_cols(self) -> Flatten<<Self::Cols as IntoIterator>::IntoIter>
     where
@@ -108,6 +681,128 @@ pub trait Grid: WithSize + Sized {
         unsafe { self.rows_unchecked(..) }.into_iter().flatten()
     }
 
+    /// Searches for an item that satisfies a predicate, returning it with
+    /// its [`Point`].
+    ///
+    /// Items are searched row by row.
+    fn find<P: FnMut(&Self::Item) -> bool>(self, mut predicate: P) -> Option<(Point, Self::Item)>
+    where
+        Self: GridRows,
+    {
+        self.indexed()
+            .flatten_rows()
+            .find(|(_, item)| predicate(item))
+    }
+
+    /// Searches for an item that satisfies a predicate, returning its
+    /// [`Point`].
+    ///
+    /// Items are searched row by row.
+    fn position<P: FnMut(Self::Item) -> bool>(self, mut predicate: P) -> Option<Point>
+    where
+        Self: GridRows,
+    {
+        self.indexed()
+            .flatten_rows()
+            .find_map(|(point, item)| if predicate(item) { Some(point) } else { None })
+    }
+
+    /// Sets the items connected to `start` which satisfy `predicate` to
+    /// `value`, returning the number of items set, or [`None`] if `start` is
+    /// out of bounds.
+    ///
+    /// Items are connected through `neighborhood`: 4-connectivity with
+    /// [`Neighborhood::VonNeumann`], 8-connectivity with
+    /// [`Neighborhood::Moore`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use tender::grid::*;
+    /// let mut grid = RowVec1D::new((3, 3), vec![
+    ///     0, 1, 0,
+    ///     1, 0, 0,
+    ///     0, 0, 1,
+    /// ]).unwrap();
+    /// let filled = (&mut grid).flood_fill((2, 0), Neighborhood::VonNeumann, |&item| item == 0, 2);
+    ///
+    /// assert_eq!(filled, Some(5));
+    /// assert_eq!(grid.as_ref(), &[0, 1, 2, 1, 2, 2, 2, 2, 1]);
+    /// ```
+    fn flood_fill<'a, T, P>(
+        self,
+        start: impl Index0D,
+        neighborhood: Neighborhood,
+        predicate: P,
+        value: T,
+    ) -> Option<usize>
+    where
+        Self: GridRows<Item = &'a mut T>,
+        T: 'a + Clone,
+        P: FnMut(&T) -> bool,
+    {
+        let size = self.size();
+        let start = start.checked(size)?;
+
+        Some(neighborhood::flood_fill(
+            self.flatten_rows().collect(),
+            size,
+            start,
+            neighborhood,
+            predicate,
+            value,
+        ))
+    }
+
+    /// Labels the connected regions of items, returning a grid of labels and
+    /// the bounding [`Rect`] of each label.
+    ///
+    /// Adjacent items (through `neighborhood`) are in the same region when
+    /// `eq` returns `true`. Labels are numbered from `0`, row by row.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use tender::geometry::*;
+    /// # use tender::grid::*;
+    /// let grid = RowVec1D::new((3, 2), vec!['a', 'a', 'b', 'b', 'a', 'b']).unwrap();
+    /// let (labels, rects) = grid.connected_components(Neighborhood::VonNeumann, |a, b| a == b);
+    ///
+    /// assert_eq!(labels.as_ref(), &[0, 0, 1, 2, 0, 1]);
+    /// assert_eq!(rects[0], Rect { x: 0..2, y: 0..2 });
+    /// assert_eq!(rects[1], Rect { x: 2..3, y: 0..2 });
+    /// ```
+    fn connected_components<F>(
+        self,
+        neighborhood: Neighborhood,
+        eq: F,
+    ) -> (RowVec1D<usize>, Vec<Rect>)
+    where
+        Self: GridRows,
+        F: FnMut(&Self::Item, &Self::Item) -> bool,
+    {
+        let size = self.size();
+
+        neighborhood::connected_components(self.flatten_rows().collect(), size, neighborhood, eq)
+    }
+
+    /// Creates a formatter printing the grid as an aligned 2D table.
+    ///
+    /// See [`Pretty`].
+    fn pretty(self) -> Pretty<Self>
+    where
+        Self: GridRows + Clone,
+    {
+        Pretty::new(self)
+    }
+
+    /// Collects the grid into an owned grid, e.g. a [`RowVec1D`].
+    ///
+    /// See [`FromGrid`].
+    fn collect_grid<B: FromGrid<Self>>(self) -> B {
+        B::from_grid(self)
+    }
+
     /// Calls `f` on each item.
     fn for_each<F: FnMut(Self::Item)>(self, f: F)
     where
@@ -116,6 +811,104 @@ pub trait Grid: WithSize + Sized {
         // SAFETY: .. is safe
         unsafe { self.items_unchecked(..) }.into_iter().for_each(f)
     }
+
+    /// Sets the items in `rect` to `value`, or returns [`None`] if out of
+    /// bounds.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use tender::grid::*;
+    /// let mut grid = RowVec1D::new((3, 2), vec![0; 6]).unwrap();
+    /// (&mut grid).fill((1.., ..1), 1).unwrap();
+    ///
+    /// assert_eq!(grid.as_ref(), &[0, 1, 1, 0, 0, 0]);
+    /// ```
+    fn fill<'a, T>(self, rect: impl Index2D, value: T) -> Option<()>
+    where
+        Self: GridItems<Item = &'a mut T>,
+        T: 'a + Clone,
+    {
+        self.crop(rect)?.for_each(|item| *item = value.clone());
+        Some(())
+    }
+
+    /// Sets the items in `rect` to the result of `f`, called with their
+    /// [`Point`], or returns [`None`] if out of bounds.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use tender::grid::*;
+    /// let mut grid = RowVec1D::new((3, 2), vec![0; 6]).unwrap();
+    /// (&mut grid).fill_with(.., |point| point.x + point.y).unwrap();
+    ///
+    /// assert_eq!(grid.as_ref(), &[0, 1, 2, 1, 2, 3]);
+    /// ```
+    fn fill_with<'a, T, F>(self, rect: impl Index2D, mut f: F) -> Option<()>
+    where
+        Self: GridRows<Item = &'a mut T>,
+        T: 'a,
+        F: FnMut(Point) -> T,
+    {
+        self.indexed()
+            .crop(rect)?
+            .flatten_rows()
+            .for_each(|(point, item)| *item = f(point));
+        Some(())
+    }
+
+    /// Clones all of the items of `src` into `self`, or returns [`None`] if
+    /// their sizes differ.
+    ///
+    /// [`Grid1D`] has a faster [`copy_from()`](Grid1D::copy_from) for
+    /// [`Grid1D`] sources of the same layout, which method calls resolve to.
+    /// Call `Grid::copy_from(&mut grid, src)` for other sources.
+    fn copy_from<'a, 'b, T, U>(self, src: U) -> Option<()>
+    where
+        Self: GridRows<Item = &'a mut T>,
+        U: GridRows<Item = &'b T>,
+        T: 'a + 'b + Clone,
+    {
+        if self.size() != src.size() {
+            return None;
+        }
+
+        self.zip(src)
+            .flatten_rows()
+            .for_each(|(item, src)| item.clone_from(src));
+        Some(())
+    }
+
+    /// Clones the items of `src` into `self` at `position`.
+    ///
+    /// Items of `src` falling outside of `self` are ignored. `position` is
+    /// signed: `src` may start above or left of `self`.
+    ///
+    /// [`Grid1D`] has a faster [`blit()`](Grid1D::blit) for [`Grid1D`]
+    /// sources of the same layout, which method calls resolve to. Call
+    /// `Grid::blit(&mut grid, position, src)` for other sources.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use tender::grid::*;
+    /// let mut grid = RowVec1D::new((3, 2), vec![0; 6]).unwrap();
+    /// let src = RowVec1D::new((2, 2), vec![1, 2, 3, 4]).unwrap();
+    /// Grid::blit(&mut grid, (2, 1), &src);
+    ///
+    /// assert_eq!(grid.as_ref(), &[0, 0, 0, 0, 0, 1]);
+    /// ```
+    fn blit<'a, 'b, T, U>(self, position: impl Into<Point<isize>>, src: U)
+    where
+        Self: GridRows<Item = &'a mut T>,
+        U: GridRows<Item = &'b T>,
+        T: 'a + 'b + Clone,
+    {
+        self.zip_at(position, src)
+            .flatten_rows()
+            .for_each(|(item, src)| item.clone_from(src));
+    }
 }
 
 macro_rules! grid1d {
@@ -128,6 +921,8 @@ macro_rules! grid1d {
         $unchecked:ident
         $(#[$checked_meta:meta])*
         $checked:ident
+        $(#[$try_meta:meta])*
+        $try:ident
     )*) => { $(
         $(#[$meta])*
         pub trait $Trait: Grid {
@@ -144,6 +939,14 @@ macro_rules! grid1d {
                 // SAFETY: index is checked
                 Some(unsafe { self.$unchecked(index) })
             }
+
+            $(#[$try_meta])*
+            fn $try(self, index: impl Index1D) -> Result<Self::$Assoc, GridError> {
+                let index = index.$try(self.size())?;
+
+                // SAFETY: index is checked
+                Ok(unsafe { self.$unchecked(index) })
+            }
         }
     )* };
 }
@@ -158,6 +961,8 @@ macro_rules! grid2d {
         $unchecked:ident
         $(#[$checked_meta:meta])*
         $checked:ident
+        $(#[$try_meta:meta])*
+        $try:ident
     )*) => { $(
         $(#[$meta])*
         pub trait $Trait: $Parent {
@@ -174,6 +979,14 @@ macro_rules! grid2d {
                 // SAFETY: index is checked
                 Some(unsafe { self.$unchecked(index) })
             }
+
+            $(#[$try_meta])*
+            fn $try(self, index: impl Index2D) -> Result<Self::$Assoc, GridError> {
+                let index = index.try_checked(self.size())?;
+
+                // SAFETY: index is checked
+                Ok(unsafe { self.$unchecked(index) })
+            }
         }
     )* };
 }
@@ -194,6 +1007,9 @@ grid1d!(
         col_unchecked
         /// Returns the column at `index`, or `None` if out of bounds.
         col
+        /// Returns the column at `index`, or a [`GridError`] if out of
+        /// bounds.
+        try_col
     /// Provides a [`Row`](GridRow::Row) 1D [`IntoIterator`].
     GridRow
         /// The type of a row.
@@ -209,6 +1025,9 @@ grid1d!(
         row_unchecked
         /// Returns the row at `index`, or `None` if out of bounds.
         row
+        /// Returns the row at `index`, or a [`GridError`] if out of
+        /// bounds.
+        try_row
 );
 
 grid2d!(
@@ -227,6 +1046,9 @@ grid2d!(
         cols_unchecked
         /// Returns the columns at `index`, or `None` if out of bounds.
         cols
+        /// Returns the columns at `index`, or a [`GridError`] if out of
+        /// bounds.
+        try_cols
     /// Provides a [`Rows`](GridRows::Rows) 2D [`IntoIterator`].
     GridRows (GridRow Row)
         /// The type of rows.
@@ -242,6 +1064,9 @@ grid2d!(
         rows_unchecked
         /// Returns the rows at `index`, or `None` if out of bounds.
         rows
+        /// Returns the rows at `index`, or a [`GridError`] if out of
+        /// bounds.
+        try_rows
     /// Provides an [`Items`](GridItems::Items) 2D [`IntoIterator`].
     GridItems (Grid Item)
         /// The type of items.
@@ -257,4 +1082,7 @@ grid2d!(
         items_unchecked
         /// Returns the items at `index`, or `None` if out of bounds.
         items
+        /// Returns the items at `index`, or a [`GridError`] if out of
+        /// bounds.
+        try_items
 );
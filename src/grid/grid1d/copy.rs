@@ -0,0 +1,117 @@
+use crate::grid::*;
+
+impl<M: Major, I: Copy, T: AsMut<[I]>> Grid1D<M, I, T> {
+    /// Copies all of the items of `src` into `self`, or returns [`None`] if
+    /// their sizes differ.
+    ///
+    /// This is a single `copy_from_slice`. See [`Grid::copy_from()`] for
+    /// other sources.
+    pub fn copy_from<U: AsRef<[I]>>(&mut self, src: &Grid1D<M, I, U>) -> Option<()> {
+        if self.size() != src.size() {
+            return None;
+        }
+
+        self.items.as_mut().copy_from_slice(src.items.as_ref());
+        Some(())
+    }
+
+    /// Copies the items of `src` into `self` at `position`.
+    ///
+    /// Items of `src` falling outside of `self` are ignored. `position` is
+    /// signed: `src` may start above or left of `self`. Major lines
+    /// (e.g. rows of [`RowGrid1D`]s) are copied with `copy_from_slice`. See
+    /// [`Grid::blit()`] for other sources.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use tender::grid::*;
+    /// let mut grid = RowVec1D::new((3, 3), vec![0; 9]).unwrap();
+    /// let src = RowVec1D::new((2, 2), vec![1, 2, 3, 4]).unwrap();
+    /// (&mut grid).blit((1, 2), &src);
+    ///
+    /// assert_eq!(grid.as_ref(), &[0, 0, 0, 0, 0, 0, 0, 1, 2]);
+    /// ```
+    pub fn blit<U: AsRef<[I]>>(
+        &mut self,
+        position: impl Into<Point<isize>>,
+        src: &Grid1D<M, I, U>,
+    ) {
+        let (rect, src_rect) = position.into().overlap(self.size(), src.size());
+        let (start, src_start) = (M::from(rect.start()), M::from(src_rect.start()));
+        let size = M::from(rect.size());
+
+        let (dst_major, src_major) = (self.size.major(), src.size.major());
+        let (dst, src) = (self.items.as_mut(), src.items.as_ref());
+
+        for i in 0..size.minor() {
+            let dst_start = (start.minor() + i) * dst_major + start.major();
+            let src_start = (src_start.minor() + i) * src_major + src_start.major();
+
+            dst[dst_start..dst_start + size.major()]
+                .copy_from_slice(&src[src_start..src_start + size.major()]);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    fn src() -> RowVec1D<i32> {
+        RowVec1D::new((2, 2), vec![1, 2, 3, 4]).unwrap()
+    }
+
+    fn blit(position: (isize, isize)) -> RowVec1D<i32> {
+        let mut grid = RowVec1D::new((3, 3), vec![0; 9]).unwrap();
+        (&mut grid).blit(position, &src());
+        grid
+    }
+
+    #[test]
+    fn blit_partly_off_grid() {
+        assert_eq!(blit((-1, -1)).as_ref(), &[4, 0, 0, 0, 0, 0, 0, 0, 0]);
+        assert_eq!(blit((2, -1)).as_ref(), &[0, 0, 3, 0, 0, 0, 0, 0, 0]);
+        assert_eq!(blit((-1, 2)).as_ref(), &[0, 0, 0, 0, 0, 0, 2, 0, 0]);
+        assert_eq!(blit((2, 2)).as_ref(), &[0, 0, 0, 0, 0, 0, 0, 0, 1]);
+    }
+
+    #[test]
+    fn blit_fully_off_grid() {
+        for position in [(3, 0), (0, 3), (-2, 0), (0, -2), (isize::MIN, isize::MAX)] {
+            assert_eq!(blit(position).as_ref(), &[0; 9]);
+        }
+    }
+
+    #[test]
+    fn fast_paths_match_generic() {
+        for x in -3..=4 {
+            for y in -3..=4 {
+                let mut fast = RowVec1D::new((3, 3), vec![0; 9]).unwrap();
+                let mut generic = fast.clone();
+                (&mut fast).blit((x, y), &src());
+                Grid::blit(&mut generic, (x, y), &src());
+                assert_eq!(fast, generic);
+
+                let src = ColVec1D::new((2, 3), vec![1, 2, 3, 4, 5, 6]).unwrap();
+                let mut fast = ColVec1D::new((3, 2), vec![0; 6]).unwrap();
+                let mut generic = fast.clone();
+                (&mut fast).blit((x, y), &src);
+                Grid::blit(&mut generic, (x, y), &src);
+                assert_eq!(fast, generic);
+            }
+        }
+
+        let mut fast = RowVec1D::new((2, 2), vec![0; 4]).unwrap();
+        let mut generic = fast.clone();
+        assert_eq!((&mut fast).copy_from(&src()), Some(()));
+        assert_eq!(Grid::copy_from(&mut generic, &src()), Some(()));
+        assert_eq!(fast, generic);
+        assert_eq!(fast, src());
+
+        let mut fast = RowVec1D::new((3, 3), vec![0; 9]).unwrap();
+        assert_eq!((&mut fast).copy_from(&src()), None);
+        assert_eq!(Grid::copy_from(&mut fast, &src()), None);
+    }
+}
@@ -11,9 +11,10 @@ pub fn index1d<M: Major>(
     (i, Range { start, end }): (usize, Range<usize>),
     size: M,
 ) -> Range<usize> {
+    let len = end - start;
     let start = index0d(M::new(start, i).into(), size);
 
-    start..start + end
+    start..start + len
 }
 
 pub fn major_index2d<M: Major>(index: Rect) -> (Range<usize>, Range<usize>) {
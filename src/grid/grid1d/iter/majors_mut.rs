@@ -1,5 +1,5 @@
 use super::*;
-use std::{marker::PhantomData, ops::Range, slice::from_raw_parts_mut};
+use std::{iter::FusedIterator, marker::PhantomData, ops::Range, slice::from_raw_parts_mut};
 
 /// A mutable 2D iterator along the major axis of a [`Grid1D`].
 #[derive(Debug)]
@@ -37,18 +37,62 @@ impl<'a, M, I, T> Iterator for MajorsMut<'a, M, I, T> {
     type Item = &'a mut [I];
 
     fn next(&mut self) -> Option<Self::Item> {
-        if self.count == 0 {
+        self.nth(0)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.count, Some(self.count))
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        if n >= self.count {
+            self.count = 0;
             None
         } else {
-            let len = self.items.len();
+            let skip = n * self.major;
+            let len = self.items.len() - skip;
             let ptr = self.items.as_mut_ptr();
 
             // SAFETY: users guaranty index is in bounds at construction
             let (slice, items) = unsafe {
-                debug_assert!(self.major <= self.items.len());
+                debug_assert!(skip + self.major <= self.items.len());
                 let (slice, items) = (
-                    from_raw_parts_mut(ptr, self.major),
-                    from_raw_parts_mut(ptr.add(self.major), len - self.major),
+                    from_raw_parts_mut(ptr.add(skip), self.major),
+                    from_raw_parts_mut(ptr.add(skip + self.major), len - self.major),
+                );
+
+                debug_assert!(self.range.end <= slice.len());
+                (slice.get_unchecked_mut(self.range.clone()), items)
+            };
+
+            self.items = items;
+            self.count -= n + 1;
+
+            Some(slice)
+        }
+    }
+}
+
+impl<'a, M, I, T> DoubleEndedIterator for MajorsMut<'a, M, I, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.nth_back(0)
+    }
+
+    fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+        if n >= self.count {
+            self.count = 0;
+            None
+        } else {
+            self.count -= n;
+            let ptr = self.items.as_mut_ptr();
+            let first = (self.count - 1) * self.major;
+
+            // SAFETY: users guaranty index is in bounds at construction
+            let (slice, items) = unsafe {
+                debug_assert!(first + self.major <= self.items.len());
+                let (items, slice) = (
+                    from_raw_parts_mut(ptr, first),
+                    from_raw_parts_mut(ptr.add(first), self.major),
                 );
 
                 debug_assert!(self.range.end <= slice.len());
@@ -62,3 +106,7 @@ impl<'a, M, I, T> Iterator for MajorsMut<'a, M, I, T> {
         }
     }
 }
+
+impl<'a, M, I, T> ExactSizeIterator for MajorsMut<'a, M, I, T> {}
+
+impl<'a, M, I, T> FusedIterator for MajorsMut<'a, M, I, T> {}
@@ -1,5 +1,5 @@
 use super::*;
-use std::{marker::PhantomData, ops::Range};
+use std::{iter::FusedIterator, marker::PhantomData, ops::Range};
 
 /// A 1D iterator along the minor axis of a [`Grid1D`].
 #[derive(Debug)]
@@ -30,12 +30,41 @@ impl<'a, M, I, T> Iterator for Minor<'a, M, I, T> {
     type Item = &'a I;
 
     fn next(&mut self) -> Option<Self::Item> {
-        if self.count == 0 {
+        self.nth(0)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.count, Some(self.count))
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        if n >= self.count {
+            self.count = 0;
+            None
+        } else {
+            let current = self.current + n * self.by;
+            self.current = current + self.by;
+            self.count -= n + 1;
+
+            // SAFETY: users guaranty index is in bounds at construction
+            debug_assert!(current < self.items.len(), "Index out of bounds");
+            Some(unsafe { self.items.get_unchecked(current) })
+        }
+    }
+}
+
+impl<'a, M, I, T> DoubleEndedIterator for Minor<'a, M, I, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.nth_back(0)
+    }
+
+    fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+        if n >= self.count {
+            self.count = 0;
             None
         } else {
-            let current = self.current;
-            self.current += self.by;
-            self.count -= 1;
+            self.count -= n + 1;
+            let current = self.current + self.count * self.by;
 
             // SAFETY: users guaranty index is in bounds at construction
             debug_assert!(current < self.items.len(), "Index out of bounds");
@@ -43,3 +72,7 @@ impl<'a, M, I, T> Iterator for Minor<'a, M, I, T> {
         }
     }
 }
+
+impl<'a, M, I, T> ExactSizeIterator for Minor<'a, M, I, T> {}
+
+impl<'a, M, I, T> FusedIterator for Minor<'a, M, I, T> {}
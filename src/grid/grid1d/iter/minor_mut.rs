@@ -1,32 +1,44 @@
 use super::*;
-use std::{marker::PhantomData, ops::Range, slice::from_raw_parts_mut};
+use std::{iter::FusedIterator, marker::PhantomData, ops::Range};
 
 /// A mutable 1D iterator along the minor axis of a [`Grid1D`].
+///
+/// Items are reached through a raw pointer, striding over the major axis, so
+/// that disjoint [`MinorMut`]s can coexist (see [`MinorsMut`]).
 #[derive(Debug)]
 pub struct MinorMut<'a, M, I, T> {
-    items:    &'a mut [I],
-    i:        usize,
+    ptr:      *mut I,
     major:    usize,
     count:    usize,
-    _phantom: PhantomData<(M, T)>,
+    _phantom: PhantomData<(&'a mut I, M, T)>,
 }
 
+// SAFETY: `MinorMut` behaves like a `&'a mut I` iterator
+unsafe impl<'a, M, I: Send, T> Send for MinorMut<'a, M, I, T> {}
+unsafe impl<'a, M, I: Sync, T> Sync for MinorMut<'a, M, I, T> {}
+
 impl<'a, M: Major, I, T: AsMut<[I]>> MinorMut<'a, M, I, T> {
     pub(crate) unsafe fn new_unchecked(grid: &'a mut Grid1D<M, I, T>, index: impl Index1D) -> Self {
         let size = grid.size;
-        let (i, Range { start, end }) = index.unchecked(size.minor());
 
-        // Splitting to the first col/row of interest
-        let major = size.major();
-        let first = start * major;
-        let items = grid.as_mut();
-        debug_assert!(first <= items.len(), "Index out of bounds");
-        let items = items.get_unchecked_mut(first..);
+        Self::from_raw(grid.as_mut().as_mut_ptr(), size, index)
+    }
+}
+
+impl<'a, M: Major, I, T> MinorMut<'a, M, I, T> {
+    /// Creates a [`MinorMut`] from the pointer to the first item of a grid.
+    ///
+    /// ### Safety
+    ///
+    /// `ptr` must be valid for `'a` and not be accessed through other means
+    /// in `index` meanwhile. `index` must be in bounds.
+    pub(crate) unsafe fn from_raw(ptr: *mut I, size: M, index: impl Index1D) -> Self {
+        let (i, Range { start, end }) = index.unchecked(size.minor());
 
         Self {
-            items,
-            i,
-            major,
+            // Wrapping since nothing is dereferenced when `count == 0`
+            ptr: ptr.wrapping_add(index0d(M::new(i, start).into(), size)),
+            major: size.major(),
             count: end - start,
             _phantom: PhantomData,
         }
@@ -37,28 +49,48 @@ impl<'a, M, I, T> Iterator for MinorMut<'a, M, I, T> {
     type Item = &'a mut I;
 
     fn next(&mut self) -> Option<Self::Item> {
-        if self.count == 0 {
+        self.nth(0)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.count, Some(self.count))
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        if n >= self.count {
+            self.count = 0;
             None
         } else {
-            let len = self.items.len();
-            let ptr = self.items.as_mut_ptr();
+            let ptr = self.ptr.wrapping_add(n * self.major);
+            self.ptr = ptr.wrapping_add(self.major);
+            self.count -= n + 1;
 
-            // SAFETY: users guaranty index is in bounds at construction
-            let (item, items) = unsafe {
-                debug_assert!(self.major <= self.items.len());
-                let (slice, items) = (
-                    from_raw_parts_mut(ptr, self.major),
-                    from_raw_parts_mut(ptr.add(self.major), len - self.major),
-                );
+            // SAFETY: users guaranty index is in bounds at construction,
+            // each item is yielded once
+            Some(unsafe { &mut *ptr })
+        }
+    }
+}
 
-                debug_assert!(self.i < slice.len());
-                (slice.get_unchecked_mut(self.i), items)
-            };
+impl<'a, M, I, T> DoubleEndedIterator for MinorMut<'a, M, I, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.nth_back(0)
+    }
 
-            self.items = items;
-            self.count -= 1;
+    fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+        if n >= self.count {
+            self.count = 0;
+            None
+        } else {
+            self.count -= n + 1;
 
-            Some(item)
+            // SAFETY: users guaranty index is in bounds at construction,
+            // each item is yielded once
+            Some(unsafe { &mut *self.ptr.add(self.count * self.major) })
         }
     }
 }
+
+impl<'a, M, I, T> ExactSizeIterator for MinorMut<'a, M, I, T> {}
+
+impl<'a, M, I, T> FusedIterator for MinorMut<'a, M, I, T> {}
@@ -1,4 +1,5 @@
 use super::*;
+use std::iter::FusedIterator;
 
 /// A 2D iterator along the minor axis of a [`Grid1D`].
 #[derive(Debug)]
@@ -19,7 +20,28 @@ impl<'a, I, T: AsRef<[I]>> Iterator for Minors<'a, RowMajor, I, T> {
     type Item = Minor<'a, RowMajor, I, T>;
 
     fn next(&mut self) -> Option<Self::Item> {
-        let index = (self.index.x.next()?, self.index.y.clone());
+        self.nth(0)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.index.x.size_hint()
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        let index = (self.index.x.nth(n)?, self.index.y.clone());
+
+        // SAFETY: users guaranty index is in bounds at construction
+        Some(unsafe { self.grid.col_unchecked(index) })
+    }
+}
+
+impl<'a, I, T: AsRef<[I]>> DoubleEndedIterator for Minors<'a, RowMajor, I, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.nth_back(0)
+    }
+
+    fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+        let index = (self.index.x.nth_back(n)?, self.index.y.clone());
 
         // SAFETY: users guaranty index is in bounds at construction
         Some(unsafe { self.grid.col_unchecked(index) })
@@ -30,9 +52,34 @@ impl<'a, I, T: AsRef<[I]>> Iterator for Minors<'a, ColMajor, I, T> {
     type Item = Minor<'a, ColMajor, I, T>;
 
     fn next(&mut self) -> Option<Self::Item> {
-        let index = (self.index.y.next()?, self.index.x.clone());
+        self.nth(0)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.index.y.size_hint()
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        let index = (self.index.y.nth(n)?, self.index.x.clone());
 
         // SAFETY: users guaranty index is in bounds at construction
         Some(unsafe { self.grid.row_unchecked(index) })
     }
 }
+
+impl<'a, I, T: AsRef<[I]>> DoubleEndedIterator for Minors<'a, ColMajor, I, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.nth_back(0)
+    }
+
+    fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+        let index = (self.index.y.nth_back(n)?, self.index.x.clone());
+
+        // SAFETY: users guaranty index is in bounds at construction
+        Some(unsafe { self.grid.row_unchecked(index) })
+    }
+}
+
+impl<'a, M, I, T> ExactSizeIterator for Minors<'a, M, I, T> where Self: Iterator {}
+
+impl<'a, M, I, T> FusedIterator for Minors<'a, M, I, T> where Self: Iterator {}
@@ -0,0 +1,169 @@
+use super::*;
+use std::{iter::FusedIterator, marker::PhantomData};
+
+/// A mutable 2D iterator along the minor axis of a [`Grid1D`].
+///
+/// Yields [`MinorMut`]s, which never overlap.
+#[derive(Debug)]
+pub struct MinorsMut<'a, M, I, T> {
+    ptr:      *mut I,
+    size:     M,
+    index:    Rect,
+    _phantom: PhantomData<(&'a mut I, T)>,
+}
+
+// SAFETY: `MinorsMut` behaves like a `&'a mut [I]` iterator
+unsafe impl<'a, M: Send, I: Send, T> Send for MinorsMut<'a, M, I, T> {}
+unsafe impl<'a, M: Sync, I: Sync, T> Sync for MinorsMut<'a, M, I, T> {}
+
+impl<'a, M: Major, I, T: AsMut<[I]>> MinorsMut<'a, M, I, T> {
+    pub(crate) unsafe fn new_unchecked(grid: &'a mut Grid1D<M, I, T>, index: impl Index2D) -> Self {
+        let size = grid.size;
+        let index = index.unchecked(size);
+
+        Self {
+            ptr: grid.as_mut().as_mut_ptr(),
+            size,
+            index,
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<'a, I, T> Iterator for MinorsMut<'a, RowMajor, I, T> {
+    type Item = MinorMut<'a, RowMajor, I, T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.nth(0)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.index.x.size_hint()
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        let index = (self.index.x.nth(n)?, self.index.y.clone());
+
+        // SAFETY: users guaranty index is in bounds at construction,
+        // columns are disjoint
+        Some(unsafe { MinorMut::from_raw(self.ptr, self.size, index) })
+    }
+}
+
+impl<'a, I, T> DoubleEndedIterator for MinorsMut<'a, RowMajor, I, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.nth_back(0)
+    }
+
+    fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+        let index = (self.index.x.nth_back(n)?, self.index.y.clone());
+
+        // SAFETY: users guaranty index is in bounds at construction,
+        // columns are disjoint
+        Some(unsafe { MinorMut::from_raw(self.ptr, self.size, index) })
+    }
+}
+
+impl<'a, I, T> Iterator for MinorsMut<'a, ColMajor, I, T> {
+    type Item = MinorMut<'a, ColMajor, I, T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.nth(0)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.index.y.size_hint()
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        let index = (self.index.y.nth(n)?, self.index.x.clone());
+
+        // SAFETY: users guaranty index is in bounds at construction,
+        // rows are disjoint
+        Some(unsafe { MinorMut::from_raw(self.ptr, self.size, index) })
+    }
+}
+
+impl<'a, I, T> DoubleEndedIterator for MinorsMut<'a, ColMajor, I, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.nth_back(0)
+    }
+
+    fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+        let index = (self.index.y.nth_back(n)?, self.index.x.clone());
+
+        // SAFETY: users guaranty index is in bounds at construction,
+        // rows are disjoint
+        Some(unsafe { MinorMut::from_raw(self.ptr, self.size, index) })
+    }
+}
+
+impl<'a, M, I, T> ExactSizeIterator for MinorsMut<'a, M, I, T> where Self: Iterator {}
+
+impl<'a, M, I, T> FusedIterator for MinorsMut<'a, M, I, T> where Self: Iterator {}
+
+#[cfg(test)]
+mod tests {
+    use crate::grid::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn row_major_cols() {
+        let mut grid = RowVec1D::new((3, 2), vec![0; 6]).unwrap();
+
+        // All columns alive at once
+        let cols = (&mut grid).cols(..).unwrap().collect::<Vec<_>>();
+        for (x, col) in cols.into_iter().enumerate() {
+            col.for_each(|item| *item += x);
+        }
+        assert_eq!(grid.as_ref(), &[0, 1, 2, 0, 1, 2]);
+
+        for (x, col) in (&mut grid).cols(..).unwrap().rev().enumerate() {
+            col.rev().enumerate().for_each(|(y, item)| *item += 10 * x + 100 * y);
+        }
+        assert_eq!(grid.as_ref(), &[120, 111, 102, 20, 11, 2]);
+
+        for col in (&mut grid).cols((1..3, 1..2)).unwrap() {
+            col.for_each(|item| *item = 0);
+        }
+        assert_eq!(grid.as_ref(), &[120, 111, 102, 20, 0, 0]);
+
+        let mut cols = (&mut grid).cols(..).unwrap();
+        let (last, first) = (cols.next_back().unwrap(), cols.next().unwrap());
+        first.chain(last).for_each(|item| *item = 7);
+        assert!(cols.next().is_some());
+        assert!(cols.next().is_none());
+        assert_eq!(grid.as_ref(), &[7, 111, 7, 7, 0, 7]);
+    }
+
+    #[test]
+    fn col_major_rows() {
+        let mut grid = ColVec1D::new((2, 3), vec![0; 6]).unwrap();
+
+        let rows = (&mut grid).rows(..).unwrap().collect::<Vec<_>>();
+        for (y, row) in rows.into_iter().enumerate() {
+            row.for_each(|item| *item += y);
+        }
+        assert_eq!(grid.as_ref(), &[0, 1, 2, 0, 1, 2]);
+
+        for (y, row) in (&mut grid).rows(..).unwrap().rev().enumerate() {
+            row.rev().enumerate().for_each(|(x, item)| *item += 10 * y + 100 * x);
+        }
+        assert_eq!(grid.as_ref(), &[120, 111, 102, 20, 11, 2]);
+
+        for row in (&mut grid).rows((1..2, 1..3)).unwrap() {
+            row.for_each(|item| *item = 0);
+        }
+        assert_eq!(grid.as_ref(), &[120, 111, 102, 20, 0, 0]);
+    }
+
+    #[test]
+    fn col_major_cols() {
+        let mut grid = ColVec1D::new((2, 3), vec![0; 6]).unwrap();
+
+        for (x, col) in (&mut grid).cols((.., 1..)).unwrap().rev().enumerate() {
+            col.iter_mut().for_each(|item| *item = x + 1);
+        }
+        assert_eq!(grid.as_ref(), &[0, 2, 2, 0, 1, 1]);
+    }
+}
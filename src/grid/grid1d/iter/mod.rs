@@ -1,17 +1,30 @@
 //! Iterators for [`Grid1D`]'s `Grid*` implementations.
 //!
 //! You will not find here `Major`/`MajorMut` since we use regular slices for
-//! that. You won't find either `MinorsMut` since there is no safe way to do
-//! this.
+//! that.
 //!
 //! Refer to these types through `Grid*`'s associated types (e.g.
 //! `&RowGrid1D::Cols`).
+//!
+//! All of them are [`DoubleEndedIterator`]s and [`ExactSizeIterator`]s, with
+//! O(1) `nth()` and `nth_back()`:
+//!
+//! ```
+//! # use tender::grid::*;
+//! let grid = RowVec1D::new((3, 3), vec![0, 1, 2, 3, 4, 5, 6, 7, 8]).unwrap();
+//! let mut cols = grid.cols(..).unwrap();
+//!
+//! assert_eq!(cols.len(), 3);
+//! assert!(cols.nth_back(1).unwrap().rev().eq(&[7, 4, 1]));
+//! assert_eq!(cols.len(), 1);
+//! ```
 
 mod majors;
 mod majors_mut;
 mod minor;
 mod minor_mut;
 mod minors;
+mod minors_mut;
 
 use super::*;
 pub use majors::*;
@@ -19,3 +32,4 @@ pub use majors_mut::*;
 pub use minor::*;
 pub use minor_mut::*;
 pub use minors::*;
+pub use minors_mut::*;
@@ -8,17 +8,25 @@
 //! a variety of collections. See our [`Slice1D`], [`Array1D`] and [`Vec1D`]
 //! aliases.
 //!
-//! Though you can use all of the `Grid*` traits immutably, it is impossible to
-//! get a mutable 2D iterator along the minor axis: `&mut ColGrid1D` does not
-//! implement [`GridRows`] nor `&mut RowGrid1D` does not implement
-//! [`GridCols`].
+//! [`Vec1D`] grids can also grow and shrink, with `insert_row`, `remove_row`,
+//! `insert_col`, `remove_col`, [`resize`](Vec1D::resize) and
+//! [`truncate`](Vec1D::truncate). Inserting/removing along the major axis
+//! moves a contiguous block of items, whereas the minor axis rebuilds the
+//! whole `Vec`.
+//!
+//! You can use all of the `Grid*` traits both immutably and mutably. Mutable
+//! 2D iterators along the minor axis (e.g. [`GridRows`] for `&mut ColGrid1D`)
+//! yield disjoint columns/rows striding over the items.
 //!
 //! Know that iterating along the minor axis is **not CPU cache friendly** and
 //! should be avoided. See the excellent
 //! [Scott Meyers' talk](https://www.youtube.com/watch?v=WDIkqP4JbkE).
 
+mod copy;
 mod index;
 pub mod iter;
+mod par;
+mod resize;
 
 use crate::grid::*;
 use index::*;
@@ -87,7 +95,7 @@ pub type RowVec1D<I> = Vec1D<RowMajor, I>;
 /// this is **not CPU cache friendly**, both immutably and mutably.
 ///
 /// You can get [`Rows`](GridRows::Rows) through the [`GridRows`] trait, though
-/// this is **not CPU cache friendly**, but only immutably.
+/// this is **not CPU cache friendly**, both immutably and mutably.
 ///
 /// You can get [`Items`](GridItems::Items) through the [`GridItems`] trait,
 /// both immutably and mutably. Items will be yielded in a column-major fashion.
@@ -104,7 +112,7 @@ pub type ColGrid1D<I, T> = Grid1D<ColMajor, I, T>;
 /// this is **not CPU cache friendly**, both immutably and mutably.
 ///
 /// You can get [`Cols`](GridCols::Cols) through the [`GridCols`] trait, though
-/// this is **not CPU cache friendly**, but only immutably.
+/// this is **not CPU cache friendly**, both immutably and mutably.
 ///
 /// You can get [`Items`](GridItems::Items) through the [`GridItems`] trait,
 /// both immutably and mutably. Items will be yielded in a row-major fashion.
@@ -174,6 +182,40 @@ impl<M: Major, I, T> Grid1D<M, I, T> {
         }
     }
 
+    /// Creates a new [`Grid1D`] if `len == x * y`, a
+    /// [`GridError::LenMismatch`] otherwise.
+    pub fn try_new<S: Into<Size>>(size: S, items: T) -> Result<Self, GridError>
+    where
+        T: AsRef<[I]>,
+    {
+        let size = size.into();
+        let len = items.as_ref().len();
+
+        if len == size.x * size.y {
+            // SAFETY: len == x * y
+            Ok(unsafe { Self::new_unchecked(size, items) })
+        } else {
+            Err(GridError::LenMismatch { len, size })
+        }
+    }
+
+    /// Creates a new [`Grid1D`] if `len == x * y`, a
+    /// [`GridError::LenMismatch`] otherwise.
+    pub fn try_new_mut<S: Into<Size>>(size: S, mut items: T) -> Result<Self, GridError>
+    where
+        T: AsMut<[I]>,
+    {
+        let size = size.into();
+        let len = items.as_mut().len();
+
+        if len == size.x * size.y {
+            // SAFETY: len == x * y
+            Ok(unsafe { Self::new_unchecked(size, items) })
+        } else {
+            Err(GridError::LenMismatch { len, size })
+        }
+    }
+
     /// Returns the underlying item collection.
     pub fn into_inner(self) -> T {
         self.items
@@ -224,6 +266,7 @@ macro_rules! grid {
 
             // Minors
             grid!(impl [ITER] $Type $M $GridMinors $Minors $minors AsRef Index2D Minors);
+            grid!(impl [ITER] $Type $M $GridMinors $Minors $minors AsMut Index2D MinorsMut (mut));
 
             // Items
             grid!(impl [ITEMS] $Type $GridMajors $Majors $majors AsRef);
@@ -0,0 +1,124 @@
+use crate::grid::*;
+use std::{num::NonZeroUsize, thread};
+
+impl<I: Send, T: AsMut<[I]>> Grid1D<RowMajor, I, T> {
+    /// Calls `f` in parallel on disjoint horizontal bands of `rows` rows (the
+    /// last band may be shorter), with the index of their first row.
+    ///
+    /// Bands are spread over [`available_parallelism()`] scoped threads.
+    ///
+    /// ### Panics
+    ///
+    /// Panics if `rows` is 0.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use tender::grid::*;
+    /// let mut grid = RowVec1D::new((2, 5), vec![0; 10]).unwrap();
+    /// grid.par_chunks_mut(2, |y, mut band| {
+    ///     (&mut band).fill(.., y).unwrap();
+    /// });
+    ///
+    /// assert_eq!(grid.as_ref(), &[0, 0, 0, 0, 2, 2, 2, 2, 4, 4]);
+    /// ```
+    ///
+    /// [`available_parallelism()`]: std::thread::available_parallelism
+    pub fn par_chunks_mut<F>(&mut self, rows: usize, f: F)
+    where
+        F: Fn(usize, RowGrid1D<I, &mut [I]>) + Sync,
+    {
+        assert!(rows != 0, "Chunk rows is zero");
+
+        let width = self.size.major();
+        if width == 0 {
+            return;
+        }
+
+        let mut bands = self
+            .items
+            .as_mut()
+            .chunks_mut(rows * width)
+            .enumerate()
+            .map(|(i, items)| {
+                let size = Size {
+                    x: width,
+                    y: items.len() / width,
+                };
+
+                // SAFETY: items are whole rows
+                (i * rows, unsafe { Grid1D::new_unchecked(size, items) })
+            })
+            .collect::<Vec<_>>()
+            .into_iter();
+
+        let threads = thread::available_parallelism().map_or(1, NonZeroUsize::get);
+        let per_thread = bands.len().div_ceil(threads);
+        let f = &f;
+
+        thread::scope(|scope| loop {
+            let group = bands.by_ref().take(per_thread).collect::<Vec<_>>();
+            if group.is_empty() {
+                break;
+            }
+
+            scope.spawn(move || {
+                for (y, band) in group {
+                    f(y, band);
+                }
+            });
+        });
+    }
+
+    /// Calls `f` in parallel on each row, with its index.
+    ///
+    /// Rows are split into one band per [`available_parallelism()`] scoped
+    /// thread. See [`par_chunks_mut()`](Self::par_chunks_mut).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use tender::grid::*;
+    /// let mut grid = RowVec1D::new((3, 2), vec![0; 6]).unwrap();
+    /// grid.par_rows_mut(|y, row| row[y] = 1);
+    ///
+    /// assert_eq!(grid.as_ref(), &[1, 0, 0, 0, 1, 0]);
+    /// ```
+    ///
+    /// [`available_parallelism()`]: std::thread::available_parallelism
+    pub fn par_rows_mut<F>(&mut self, f: F)
+    where
+        F: Fn(usize, &mut [I]) + Sync,
+    {
+        let width = self.size.major();
+        if width == 0 {
+            return;
+        }
+
+        self.par_bands_mut(None, |y, mut band| {
+            for (i, row) in band.as_mut().chunks_exact_mut(width).enumerate() {
+                f(y + i, row);
+            }
+        });
+    }
+
+    /// Calls `f` in parallel on `bands` horizontal bands, or on one band per
+    /// [`available_parallelism()`](thread::available_parallelism) thread if
+    /// `None`.
+    ///
+    /// There is one band per row when there are more bands than rows.
+    pub(crate) fn par_bands_mut<F>(&mut self, bands: Option<usize>, f: F)
+    where
+        F: Fn(usize, RowGrid1D<I, &mut [I]>) + Sync,
+    {
+        let height = self.size.minor();
+        if height == 0 {
+            return;
+        }
+
+        let bands =
+            bands.unwrap_or_else(|| thread::available_parallelism().map_or(1, NonZeroUsize::get));
+
+        self.par_chunks_mut(height.div_ceil(bands.max(1)), f);
+    }
+}
@@ -0,0 +1,342 @@
+use crate::grid::*;
+use std::mem;
+
+impl<M: Major, I> Vec1D<M, I> {
+    /// Resizes `self` to `size`, filling new items with `fill`.
+    ///
+    /// Items keep their [`Point`] if still in bounds.
+    pub fn resize(&mut self, size: impl Into<Size>, fill: I)
+    where
+        I: Clone,
+    {
+        let size = M::from(size.into());
+        let (major, minor) = (size.major(), size.minor());
+
+        self.truncate_minor(minor);
+
+        if major != self.size.major() {
+            self.resize_majors(major, |_| fill.clone());
+        }
+
+        // Fill the new lines aside, so that a panicking `clone` leaves `self`
+        // untouched
+        let lines = vec![fill; major * minor - self.items.len()];
+        self.items.extend(lines);
+        self.size = size;
+    }
+
+    /// Shrinks `self` to fit in `size`.
+    ///
+    /// Items keep their [`Point`] if still in bounds. Has no effect on axis
+    /// which are already smaller than `size`.
+    pub fn truncate(&mut self, size: impl Into<Size>) {
+        let size = M::from(size.into().min(self.size.into()));
+        let (major, minor) = (size.major(), size.minor());
+
+        self.truncate_minor(minor);
+
+        if major != self.size.major() {
+            let old = self.size.major();
+            let mut i = 0;
+
+            self.items.retain(|_| {
+                let keep = i % old < major;
+                i += 1;
+                keep
+            });
+        }
+
+        self.size = size;
+    }
+
+    fn truncate_minor(&mut self, minor: usize) {
+        if minor < self.size.minor() {
+            self.items.truncate(minor * self.size.major());
+            self.size = M::new(self.size.major(), minor);
+        }
+    }
+
+    /// Rebuilds every major line to a length of `major`, calling `fill` with
+    /// the index of the line for missing items.
+    fn resize_majors(&mut self, major: usize, mut fill: impl FnMut(usize) -> I) {
+        let (old, minor) = (self.size.major(), self.size.minor());
+        let mut items = self.take_items().into_iter();
+        let mut resized = Vec::with_capacity(major * minor);
+
+        for i in 0..minor {
+            resized.extend(items.by_ref().take(major.min(old)));
+            items
+                .by_ref()
+                .take(old.saturating_sub(major))
+                .for_each(drop);
+            resized.extend((old..major).map(|_| fill(i)));
+        }
+
+        self.items = resized;
+        self.size = M::new(major, minor);
+    }
+
+    fn insert_major(&mut self, index: usize, line: impl IntoIterator<Item = I>) {
+        let (major, minor) = (self.size.major(), self.size.minor());
+        assert!(index <= minor, "Index out of bounds");
+
+        let line = line.into_iter().collect::<Vec<_>>();
+        let inserted = line.len();
+        assert!(
+            minor == 0 || inserted == major,
+            "Inserted length does not match"
+        );
+
+        let start = index * major;
+        self.items.splice(start..start, line);
+        self.size = M::new(inserted, minor + 1);
+    }
+
+    fn remove_major(&mut self, index: usize) -> Vec<I> {
+        let (major, minor) = (self.size.major(), self.size.minor());
+        assert!(index < minor, "Index out of bounds");
+
+        let start = index * major;
+        let line = self.items.drain(start..start + major).collect();
+        self.size = M::new(major, minor - 1);
+
+        line
+    }
+
+    fn insert_minor(&mut self, index: usize, line: impl IntoIterator<Item = I>) {
+        let (major, minor) = (self.size.major(), self.size.minor());
+        assert!(index <= major, "Index out of bounds");
+
+        let line = line.into_iter().collect::<Vec<_>>();
+
+        // The first line of an empty grid gives its length
+        let minor = if major == 0 { line.len() } else { minor };
+        assert!(line.len() == minor, "Inserted length does not match");
+
+        let mut line = line.into_iter();
+        let mut items = self.take_items().into_iter();
+        let mut inserted = Vec::with_capacity((major + 1) * minor);
+
+        for _ in 0..minor {
+            inserted.extend(items.by_ref().take(index));
+            inserted.extend(line.next());
+            inserted.extend(items.by_ref().take(major - index));
+        }
+
+        self.items = inserted;
+        self.size = M::new(major + 1, minor);
+    }
+
+    /// Takes the items out of `self`, leaving an empty grid.
+    ///
+    /// The size is reset first, so that `self` stays valid if rebuilding the
+    /// items panics (e.g. in `fill` or the caller's iterator).
+    fn take_items(&mut self) -> Vec<I> {
+        self.size = M::new(0, 0);
+
+        mem::take(&mut self.items)
+    }
+
+    fn remove_minor(&mut self, index: usize) -> Vec<I> {
+        let (major, minor) = (self.size.major(), self.size.minor());
+        assert!(index < major, "Index out of bounds");
+
+        let mut items = self.take_items().into_iter();
+        let mut kept = Vec::with_capacity((major - 1) * minor);
+        let mut line = Vec::with_capacity(minor);
+
+        for _ in 0..minor {
+            kept.extend(items.by_ref().take(index));
+            line.extend(items.next());
+            kept.extend(items.by_ref().take(major - index - 1));
+        }
+
+        self.items = kept;
+        self.size = M::new(major - 1, minor);
+
+        line
+    }
+}
+
+macro_rules! resize {
+    ($(
+        $Type:ident
+            $insert_row:ident $remove_row:ident
+            $insert_col:ident $remove_col:ident
+    )*) => { $(
+        impl<I> $Type<I> {
+            /// Inserts `row` at `index`, shifting all rows after it downwards.
+            ///
+            /// If `self` has no rows, `row` sets the width.
+            ///
+            /// ### Panics
+            ///
+            /// Panics if `index > height` or if the length of `row` does not
+            /// match the width.
+            pub fn insert_row(&mut self, index: usize, row: impl IntoIterator<Item = I>) {
+                self.$insert_row(index, row)
+            }
+
+            /// Removes and returns the row at `index`, shifting all rows after
+            /// it upwards.
+            ///
+            /// ### Panics
+            ///
+            /// Panics if `index >= height`.
+            pub fn remove_row(&mut self, index: usize) -> Vec<I> {
+                self.$remove_row(index)
+            }
+
+            /// Inserts `col` at `index`, shifting all columns after it to the
+            /// right.
+            ///
+            /// If `self` has no columns, `col` sets the height.
+            ///
+            /// ### Panics
+            ///
+            /// Panics if `index > width` or if the length of `col` does not
+            /// match the height.
+            pub fn insert_col(&mut self, index: usize, col: impl IntoIterator<Item = I>) {
+                self.$insert_col(index, col)
+            }
+
+            /// Removes and returns the column at `index`, shifting all columns
+            /// after it to the left.
+            ///
+            /// ### Panics
+            ///
+            /// Panics if `index >= width`.
+            pub fn remove_col(&mut self, index: usize) -> Vec<I> {
+                self.$remove_col(index)
+            }
+        }
+    )* };
+}
+
+resize!(
+    RowVec1D
+        insert_major remove_major
+        insert_minor remove_minor
+    ColVec1D
+        insert_minor remove_minor
+        insert_major remove_major
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+    use std::panic::{catch_unwind, AssertUnwindSafe};
+
+    fn sample() -> RowVec1D<i32> {
+        RowVec1D::new((3, 2), vec![1, 2, 3, 4, 5, 6]).unwrap()
+    }
+
+    fn is_valid<M: Major, I>(grid: &Vec1D<M, I>) {
+        let size = grid.size();
+        assert_eq!(grid.as_ref().len(), size.x * size.y);
+    }
+
+    #[test]
+    fn insert_remove_rows() {
+        let mut grid = sample();
+
+        grid.insert_row(1, vec![7, 8, 9]);
+        assert_eq!(grid.size(), Size { x: 3, y: 3 });
+        assert_eq!(grid.as_ref(), &[1, 2, 3, 7, 8, 9, 4, 5, 6]);
+
+        assert_eq!(grid.remove_row(0), [1, 2, 3]);
+        assert_eq!(grid.as_ref(), &[7, 8, 9, 4, 5, 6]);
+
+        grid.insert_row(2, vec![0, 0, 0]);
+        assert_eq!(grid.as_ref(), &[7, 8, 9, 4, 5, 6, 0, 0, 0]);
+    }
+
+    #[test]
+    fn insert_remove_cols() {
+        let mut grid = sample();
+
+        grid.insert_col(1, vec![7, 8]);
+        assert_eq!(grid.size(), Size { x: 4, y: 2 });
+        assert_eq!(grid.as_ref(), &[1, 7, 2, 3, 4, 8, 5, 6]);
+
+        assert_eq!(grid.remove_col(3), [3, 6]);
+        assert_eq!(grid.as_ref(), &[1, 7, 2, 4, 8, 5]);
+
+        let mut grid = ColVec1D::new((2, 2), vec![1, 2, 3, 4]).unwrap();
+        grid.insert_row(2, vec![5, 6]);
+        assert_eq!(grid.size(), Size { x: 2, y: 3 });
+        assert_eq!(grid.as_ref(), &[1, 2, 5, 3, 4, 6]);
+        assert_eq!(grid.remove_col(0), [1, 2, 5]);
+    }
+
+    #[test]
+    fn insert_into_empty() {
+        let mut grid = RowVec1D::<i32>::new((0, 0), vec![]).unwrap();
+        grid.insert_col(0, vec![1, 2]);
+        assert_eq!(grid.size(), Size { x: 1, y: 2 });
+
+        let mut grid = RowVec1D::<i32>::new((0, 0), vec![]).unwrap();
+        grid.insert_row(0, vec![1, 2]);
+        assert_eq!(grid.size(), Size { x: 2, y: 1 });
+    }
+
+    #[test]
+    fn resize_truncate() {
+        let mut grid = sample();
+
+        grid.resize((4, 3), 0);
+        assert_eq!(grid.as_ref(), &[1, 2, 3, 0, 4, 5, 6, 0, 0, 0, 0, 0]);
+
+        grid.truncate((2, 5));
+        assert_eq!(grid.size(), Size { x: 2, y: 3 });
+        assert_eq!(grid.as_ref(), &[1, 2, 4, 5, 0, 0]);
+
+        grid.resize((1, 1), 0);
+        assert_eq!(grid.as_ref(), &[1]);
+    }
+
+    #[test]
+    fn length_mismatch_keeps_grid_valid() {
+        let mut grid = sample();
+
+        let panicked = catch_unwind(AssertUnwindSafe(|| grid.insert_row(0, vec![0])));
+        assert!(panicked.is_err());
+        is_valid(&grid);
+        assert_eq!(grid.as_ref(), &[1, 2, 3, 4, 5, 6]);
+
+        for col in [vec![0], vec![0, 0, 0]] {
+            let mut grid = sample();
+
+            let panicked = catch_unwind(AssertUnwindSafe(|| grid.insert_col(1, col)));
+            assert!(panicked.is_err());
+            is_valid(&grid);
+            assert_eq!(grid, sample());
+        }
+    }
+
+    #[test]
+    fn panicking_fill_keeps_grid_valid() {
+        #[derive(Debug, PartialEq)]
+        struct Bomb(i32);
+
+        impl Clone for Bomb {
+            fn clone(&self) -> Self {
+                panic!("Bomb")
+            }
+        }
+
+        let mut grid = RowVec1D::new((2, 1), vec![Bomb(1), Bomb(2)]).unwrap();
+
+        let panicked = catch_unwind(AssertUnwindSafe(|| grid.resize((3, 1), Bomb(0))));
+        assert!(panicked.is_err());
+        is_valid(&grid);
+
+        let mut grid = RowVec1D::new((2, 1), vec![Bomb(1), Bomb(2)]).unwrap();
+
+        let panicked = catch_unwind(AssertUnwindSafe(|| grid.resize((2, 3), Bomb(0))));
+        assert!(panicked.is_err());
+        is_valid(&grid);
+        assert_eq!(grid.as_ref(), &[Bomb(1), Bomb(2)]);
+    }
+}
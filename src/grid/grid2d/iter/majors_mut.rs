@@ -1,5 +1,5 @@
 pub use super::*;
-use std::{marker::PhantomData, ops::Range, slice::from_raw_parts_mut};
+use std::{iter::FusedIterator, marker::PhantomData, ops::Range, slice::from_raw_parts_mut};
 
 /// A mutable 2D iterator along the major axis of an [`Grid2D`].
 #[derive(Debug)]
@@ -51,29 +51,72 @@ impl<'a, M, I: 'a, T, U: AsMut<[I]>> Iterator for MajorsMut<'a, M, I, T, U> {
     type Item = &'a mut [I];
 
     fn next(&mut self) -> Option<Self::Item> {
+        self.nth(0)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.items.len(), Some(self.items.len()))
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
         let len = self.items.len();
 
-        if len == 0 {
+        if n >= len {
+            self.items = &mut [];
             None
         } else {
             // SAFETY: users guaranty index is in bounds at construction
             Some(unsafe {
-                let ptr = (&mut self.items).as_mut_ptr();
+                let ptr = self.items.as_mut_ptr();
                 let range = self.range.clone();
 
-                debug_assert!(1 <= len);
-                let slice = from_raw_parts_mut(ptr, 1);
-                self.items = from_raw_parts_mut(ptr.add(1), len - 1);
+                debug_assert!(n < len);
+                let slice = from_raw_parts_mut(ptr.add(n), 1);
+                self.items = from_raw_parts_mut(ptr.add(n + 1), len - n - 1);
 
                 debug_assert!(slice.len() == 1);
                 let slice = slice.get_unchecked_mut(0).as_mut();
 
                 debug_assert!(range.start <= range.end);
                 debug_assert!(range.end <= slice.len());
-                let slice = slice.get_unchecked_mut(range);
+                slice.get_unchecked_mut(range)
+            })
+        }
+    }
+}
+
+impl<'a, M, I: 'a, T, U: AsMut<[I]>> DoubleEndedIterator for MajorsMut<'a, M, I, T, U> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.nth_back(0)
+    }
+
+    fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+        let len = self.items.len();
+
+        if n >= len {
+            self.items = &mut [];
+            None
+        } else {
+            // SAFETY: users guaranty index is in bounds at construction
+            Some(unsafe {
+                let ptr = self.items.as_mut_ptr();
+                let range = self.range.clone();
+
+                debug_assert!(n < len);
+                let slice = from_raw_parts_mut(ptr.add(len - n - 1), 1);
+                self.items = from_raw_parts_mut(ptr, len - n - 1);
 
-                slice
+                debug_assert!(slice.len() == 1);
+                let slice = slice.get_unchecked_mut(0).as_mut();
+
+                debug_assert!(range.start <= range.end);
+                debug_assert!(range.end <= slice.len());
+                slice.get_unchecked_mut(range)
             })
         }
     }
 }
+
+impl<'a, M, I: 'a, T, U: AsMut<[I]>> ExactSizeIterator for MajorsMut<'a, M, I, T, U> {}
+
+impl<'a, M, I: 'a, T, U: AsMut<[I]>> FusedIterator for MajorsMut<'a, M, I, T, U> {}
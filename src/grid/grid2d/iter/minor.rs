@@ -1,5 +1,5 @@
 use super::*;
-use std::{marker::PhantomData, ops::Range};
+use std::{iter::FusedIterator, marker::PhantomData, ops::Range};
 
 /// A 1D iterator along the minor axis of an [`Grid2D`].
 #[derive(Debug)]
@@ -36,7 +36,15 @@ impl<'a, M, I: 'a, T, U: AsRef<[I]>> Iterator for Minor<'a, M, I, T, U> {
     type Item = &'a I;
 
     fn next(&mut self) -> Option<Self::Item> {
-        let start = self.range.next()?;
+        self.nth(0)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.range.size_hint()
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        let start = self.range.nth(n)?;
 
         // SAFETY: users guaranty index is in bounds at construction
         Some(unsafe {
@@ -48,3 +56,26 @@ impl<'a, M, I: 'a, T, U: AsRef<[I]>> Iterator for Minor<'a, M, I, T, U> {
         })
     }
 }
+
+impl<'a, M, I: 'a, T, U: AsRef<[I]>> DoubleEndedIterator for Minor<'a, M, I, T, U> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.nth_back(0)
+    }
+
+    fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+        let end = self.range.nth_back(n)?;
+
+        // SAFETY: users guaranty index is in bounds at construction
+        Some(unsafe {
+            debug_assert!(end < self.items.len());
+            let items = self.items.get_unchecked(end).as_ref();
+
+            debug_assert!(self.index < items.len());
+            items.get_unchecked(self.index)
+        })
+    }
+}
+
+impl<'a, M, I, T, U> ExactSizeIterator for Minor<'a, M, I, T, U> where Self: Iterator {}
+
+impl<'a, M, I, T, U> FusedIterator for Minor<'a, M, I, T, U> where Self: Iterator {}
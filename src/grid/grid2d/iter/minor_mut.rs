@@ -1,14 +1,30 @@
 use super::*;
-use std::{marker::PhantomData, ops::Range, slice::from_raw_parts_mut};
+use std::{iter::FusedIterator, marker::PhantomData, ops::Range, slice::from_raw_parts_mut, sync::Arc};
 
 /// A mutable 1D iterator along the minor axis of an [`Grid2D`].
 #[derive(Debug)]
 pub struct MinorMut<'a, M, I, T, U> {
-    items:   &'a mut [U],
+    lines:   Lines<'a, I, U>,
     index:   usize,
-    phantom: PhantomData<(M, I, T)>,
+    phantom: PhantomData<(M, T)>,
 }
 
+/// The lines a [`MinorMut`] iterates through.
+#[derive(Debug)]
+enum Lines<'a, I, U> {
+    /// The lines of a lone [`MinorMut`].
+    Owned(&'a mut [U]),
+    /// Pointers to the lines shared by the [`MinorMut`]s of a [`MinorsMut`].
+    ///
+    /// Lines are only converted `AsMut<[I]>` once, so that no `&mut U`
+    /// aliases the items of another [`MinorMut`].
+    Shared(Arc<[*mut I]>, Range<usize>, PhantomData<&'a mut I>),
+}
+
+// SAFETY: `MinorMut` behaves like a `&'a mut I` iterator
+unsafe impl<'a, M, I: Send, T, U: Send> Send for MinorMut<'a, M, I, T, U> {}
+unsafe impl<'a, M, I: Sync, T, U: Sync> Sync for MinorMut<'a, M, I, T, U> {}
+
 impl<'a, M: Major, I, T: AsMut<[U]>, U> MinorMut<'a, M, I, T, U> {
     pub(crate) unsafe fn new_unchecked(
         grid: &'a mut Grid2D<M, I, T, U>,
@@ -25,7 +41,7 @@ impl<'a, M: Major, I, T: AsMut<[U]>, U> MinorMut<'a, M, I, T, U> {
         let items = items.get_unchecked_mut(start..end);
 
         Self {
-            items,
+            lines: Lines::Owned(items),
             index,
             phantom: PhantomData,
         }
@@ -46,29 +62,119 @@ impl<'a, M: Major, I, T: AsMut<[U]>, U> MinorMut<'a, M, I, T, U> {
     }
 }
 
+impl<'a, M, I, T, U> MinorMut<'a, M, I, T, U> {
+    /// Creates a [`MinorMut`] from pointers to the first item of each line.
+    ///
+    /// ### Safety
+    ///
+    /// Lines must be valid for `'a`, with `index` in bounds. Items at `index`
+    /// must not be accessed through other means meanwhile.
+    pub(crate) unsafe fn shared(lines: Arc<[*mut I]>, range: Range<usize>, index: usize) -> Self {
+        Self {
+            lines: Lines::Shared(lines, range, PhantomData),
+            index,
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<'a, M, I, T, U> MinorMut<'a, M, I, T, U> {
+    fn remaining(&self) -> usize {
+        match &self.lines {
+            Lines::Owned(items) => items.len(),
+            Lines::Shared(_, range, _) => range.len(),
+        }
+    }
+}
+
 impl<'a, M, I: 'a, T, U: AsMut<[I]>> Iterator for MinorMut<'a, M, I, T, U> {
     type Item = &'a mut I;
 
     fn next(&mut self) -> Option<Self::Item> {
-        let len = self.items.len();
+        self.nth(0)
+    }
 
-        if len == 0 {
-            None
-        } else {
-            // SAFETY: constructor guaranty index is in bounds
-            Some(unsafe {
-                let ptr = self.items.as_mut_ptr();
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining(), Some(self.remaining()))
+    }
 
-                debug_assert!(1 <= len);
-                let slice = from_raw_parts_mut(ptr, 1);
-                self.items = from_raw_parts_mut(ptr.add(1), len - 1);
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        match &mut self.lines {
+            Lines::Owned(items) => {
+                let len = items.len();
 
-                debug_assert!(slice.len() == 1);
-                let slice = slice.get_unchecked_mut(0).as_mut();
+                if n >= len {
+                    *items = &mut [];
+                    None
+                } else {
+                    // SAFETY: constructor guaranty index is in bounds
+                    Some(unsafe {
+                        let ptr = items.as_mut_ptr();
 
-                debug_assert!(self.index < slice.len());
-                slice.get_unchecked_mut(self.index)
-            })
+                        debug_assert!(n < len);
+                        let slice = from_raw_parts_mut(ptr.add(n), 1);
+                        *items = from_raw_parts_mut(ptr.add(n + 1), len - n - 1);
+
+                        debug_assert!(slice.len() == 1);
+                        let slice = slice.get_unchecked_mut(0).as_mut();
+
+                        debug_assert!(self.index < slice.len());
+                        slice.get_unchecked_mut(self.index)
+                    })
+                }
+            }
+            Lines::Shared(lines, range, _) => {
+                let line = range.nth(n)?;
+
+                // SAFETY: constructor guaranty index is in bounds, each item
+                // is yielded once
+                Some(unsafe { &mut *lines.get_unchecked(line).add(self.index) })
+            }
+        }
+    }
+}
+
+impl<'a, M, I: 'a, T, U: AsMut<[I]>> DoubleEndedIterator for MinorMut<'a, M, I, T, U> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.nth_back(0)
+    }
+
+    fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+        match &mut self.lines {
+            Lines::Owned(items) => {
+                let len = items.len();
+
+                if n >= len {
+                    *items = &mut [];
+                    None
+                } else {
+                    // SAFETY: constructor guaranty index is in bounds
+                    Some(unsafe {
+                        let ptr = items.as_mut_ptr();
+
+                        debug_assert!(n < len);
+                        let slice = from_raw_parts_mut(ptr.add(len - n - 1), 1);
+                        *items = from_raw_parts_mut(ptr, len - n - 1);
+
+                        debug_assert!(slice.len() == 1);
+                        let slice = slice.get_unchecked_mut(0).as_mut();
+
+                        debug_assert!(self.index < slice.len());
+                        slice.get_unchecked_mut(self.index)
+                    })
+                }
+            }
+            Lines::Shared(lines, range, _) => {
+                let line = range.nth_back(n)?;
+
+                // SAFETY: constructor guaranty index is in bounds, each item
+                // is yielded once
+                Some(unsafe { &mut *lines.get_unchecked(line).add(self.index) })
+            }
         }
     }
 }
+
+impl<'a, M, I: 'a, T, U: AsMut<[I]>> ExactSizeIterator for MinorMut<'a, M, I, T, U> {}
+
+impl<'a, M, I: 'a, T, U: AsMut<[I]>> FusedIterator for MinorMut<'a, M, I, T, U> {}
@@ -0,0 +1,160 @@
+use super::*;
+use std::{iter::FusedIterator, marker::PhantomData, ops::Range, sync::Arc};
+
+/// A mutable 2D iterator along the minor axis of an [`Grid2D`].
+///
+/// Yields [`MinorMut`]s, which never overlap.
+#[derive(Debug)]
+pub struct MinorsMut<'a, M, I, T, U> {
+    lines:   Arc<[*mut I]>,
+    majors:  Range<usize>,
+    phantom: PhantomData<(&'a mut I, M, T, U)>,
+}
+
+// SAFETY: `MinorsMut` behaves like a `&'a mut [I]` iterator
+unsafe impl<'a, M, I: Send, T, U: Send> Send for MinorsMut<'a, M, I, T, U> {}
+unsafe impl<'a, M, I: Sync, T, U: Sync> Sync for MinorsMut<'a, M, I, T, U> {}
+
+impl<'a, M: Major, I, T: AsMut<[U]>, U: AsMut<[I]>> MinorsMut<'a, M, I, T, U> {
+    pub(crate) unsafe fn new_unchecked(
+        grid: &'a mut Grid2D<M, I, T, U>,
+        index: impl Index2D,
+    ) -> Self {
+        let index = index.unchecked(grid.size);
+        let start = M::from(index.start());
+        let end = M::from(index.end());
+
+        // Pointers to the first item of each line of interest
+        let lines = grid.as_mut();
+        debug_assert!(end.minor() <= lines.len());
+        let lines = lines
+            .get_unchecked_mut(start.minor()..end.minor())
+            .iter_mut()
+            .map(|line| line.as_mut().as_mut_ptr())
+            .collect();
+
+        Self {
+            lines,
+            majors: start.major()..end.major(),
+            phantom: PhantomData,
+        }
+    }
+
+    pub(crate) unsafe fn cols_unchecked(
+        grid: &'a mut Grid2D<M, I, T, U>,
+        index: impl Index2D,
+    ) -> Self {
+        Self::new_unchecked(grid, index)
+    }
+
+    pub(crate) unsafe fn rows_unchecked(
+        grid: &'a mut Grid2D<M, I, T, U>,
+        index: impl Index2D,
+    ) -> Self {
+        Self::new_unchecked(grid, index)
+    }
+}
+
+impl<'a, M, I: 'a, T, U: 'a + AsMut<[I]>> Iterator for MinorsMut<'a, M, I, T, U> {
+    type Item = MinorMut<'a, M, I, T, U>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.nth(0)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.majors.size_hint()
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        let index = self.majors.nth(n)?;
+
+        // SAFETY: users guaranty index is in bounds at construction,
+        // columns/rows are disjoint
+        Some(unsafe { MinorMut::shared(self.lines.clone(), 0..self.lines.len(), index) })
+    }
+}
+
+impl<'a, M, I: 'a, T, U: 'a + AsMut<[I]>> DoubleEndedIterator for MinorsMut<'a, M, I, T, U> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.nth_back(0)
+    }
+
+    fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+        let index = self.majors.nth_back(n)?;
+
+        // SAFETY: users guaranty index is in bounds at construction,
+        // columns/rows are disjoint
+        Some(unsafe { MinorMut::shared(self.lines.clone(), 0..self.lines.len(), index) })
+    }
+}
+
+impl<'a, M, I, T, U> ExactSizeIterator for MinorsMut<'a, M, I, T, U> where Self: Iterator {}
+
+impl<'a, M, I, T, U> FusedIterator for MinorsMut<'a, M, I, T, U> where Self: Iterator {}
+
+#[cfg(test)]
+mod tests {
+    use crate::grid::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn row_major_cols() {
+        let mut grid = RowVec2D::new((3, 2), vec![vec![0; 3]; 2]).unwrap();
+
+        // All columns alive at once
+        let cols = (&mut grid).cols(..).unwrap().collect::<Vec<_>>();
+        for (x, col) in cols.into_iter().enumerate() {
+            col.for_each(|item| *item += x);
+        }
+        assert_eq!(grid.as_ref(), &[[0, 1, 2], [0, 1, 2]]);
+
+        for (x, col) in (&mut grid).cols(..).unwrap().rev().enumerate() {
+            col.rev().enumerate().for_each(|(y, item)| *item += 10 * x + 100 * y);
+        }
+        assert_eq!(grid.as_ref(), &[[120, 111, 102], [20, 11, 2]]);
+
+        for col in (&mut grid).cols((1..3, 1..2)).unwrap() {
+            col.for_each(|item| *item = 0);
+        }
+        assert_eq!(grid.as_ref(), &[[120, 111, 102], [20, 0, 0]]);
+
+        let mut cols = (&mut grid).cols(..).unwrap();
+        let (last, first) = (cols.next_back().unwrap(), cols.next().unwrap());
+        first.chain(last).for_each(|item| *item = 7);
+        assert!(cols.next().is_some());
+        assert!(cols.next().is_none());
+        assert_eq!(grid.as_ref(), &[[7, 111, 7], [7, 0, 7]]);
+    }
+
+    #[test]
+    fn col_major_rows() {
+        let mut grid = ColVec2D::new((2, 3), vec![vec![0; 3]; 2]).unwrap();
+
+        let rows = (&mut grid).rows(..).unwrap().collect::<Vec<_>>();
+        for (y, row) in rows.into_iter().enumerate() {
+            row.for_each(|item| *item += y);
+        }
+        assert_eq!(grid.as_ref(), &[[0, 1, 2], [0, 1, 2]]);
+
+        for (y, row) in (&mut grid).rows(..).unwrap().rev().enumerate() {
+            row.rev().enumerate().for_each(|(x, item)| *item += 10 * y + 100 * x);
+        }
+        assert_eq!(grid.as_ref(), &[[120, 111, 102], [20, 11, 2]]);
+
+        for row in (&mut grid).rows((1..2, 1..3)).unwrap() {
+            row.for_each(|item| *item = 0);
+        }
+        assert_eq!(grid.as_ref(), &[[120, 111, 102], [20, 0, 0]]);
+    }
+
+    #[test]
+    fn col_major_cols() {
+        let mut grid = ColVec2D::new((2, 3), vec![vec![0; 3]; 2]).unwrap();
+
+        for (x, col) in (&mut grid).cols((.., 1..)).unwrap().rev().enumerate() {
+            col.iter_mut().for_each(|item| *item = x + 1);
+        }
+        assert_eq!(grid.as_ref(), &[[0, 2, 2], [0, 1, 1]]);
+    }
+}
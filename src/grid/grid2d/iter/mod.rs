@@ -5,6 +5,7 @@ mod majors_mut;
 mod minor;
 mod minor_mut;
 mod minors;
+mod minors_mut;
 
 use super::*;
 pub use majors::*;
@@ -12,3 +13,4 @@ pub use majors_mut::*;
 pub use minor::*;
 pub use minor_mut::*;
 pub use minors::*;
+pub use minors_mut::*;
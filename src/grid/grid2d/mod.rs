@@ -7,10 +7,9 @@
 //! Since [`Grid2D`] wraps collections that `AsRef<[AsRef<[I]>]>`, we can use it
 //! with a variety of collections. See our [`Array2D`] and [`Vec2D`] aliases.
 //!
-//! Though you can use all of the `Grid*` traits immutably, it is impossible to
-//! get a mutable 2D iterator along the minor axis: `&mut ColGrid2D` does not
-//! implement [`GridRows`] nor `&mut RowGrid2D` does not implement
-//! [`GridCols`].
+//! You can use all of the `Grid*` traits both immutably and mutably. Mutable
+//! 2D iterators along the minor axis (e.g. [`GridRows`] for `&mut ColGrid2D`)
+//! allocate pointers to the lines they go through.
 //!
 //! Know that iterating along the minor axis is **not CPU cache friendly** and
 //! should be avoided. See the excellent
@@ -38,7 +37,7 @@ pub type ColArray2D<I, const X: usize, const Y: usize> = Array2D<ColMajor, I, Y,
 ///
 /// See [`Grid2D`].  
 /// See [`Array2D`], [`ColArray2D`].
-pub type RowArray2D<I, const X: usize, const Y: usize> = Array2D<ColMajor, I, X, Y>;
+pub type RowArray2D<I, const X: usize, const Y: usize> = Array2D<RowMajor, I, X, Y>;
 
 /// A grid from a `Vec` of `Vec`s.
 ///
@@ -67,7 +66,7 @@ pub type RowVec2D<I> = Vec2D<RowMajor, I>;
 /// this is **not CPU cache friendly**, both immutably and mutably.
 ///
 /// You can get [`Rows`](GridRows::Rows) through the [`GridRows`] trait, though
-/// this is **not CPU cache friendly**, but only immutably.
+/// this is **not CPU cache friendly**, both immutably and mutably.
 ///
 /// You can get [`Items`](GridItems::Items) through the [`GridItems`] trait,
 /// both immutably and mutably. Items will be yielded in a column-major fashion.
@@ -84,7 +83,7 @@ pub type ColGrid2D<I, T, U> = Grid2D<ColMajor, I, T, U>;
 /// this is **not CPU cache friendly**, both immutably and mutably.
 ///
 /// You can get [`Cols`](GridCols::Cols) through the [`GridCols`] trait, though
-/// this is **not CPU cache friendly**, but only immutably.
+/// this is **not CPU cache friendly**, both immutably and mutably.
 ///
 /// You can get [`Items`](GridItems::Items) through the [`GridItems`] trait,
 /// both immutably and mutably. Items will be yielded in a row-major fashion.
@@ -119,6 +118,97 @@ impl<M: Major, I, T, U> Grid2D<M, I, T, U> {
             phantom: PhantomData,
         }
     }
+
+    /// Creates a new [`Grid2D`] if there are `minor` inner collections of
+    /// `major` items each, `None` otherwise.
+    pub fn new(size: impl Into<Size>, items: T) -> Option<Self>
+    where
+        T: AsRef<[U]>,
+        U: AsRef<[I]>,
+    {
+        Self::try_new(size, items).ok()
+    }
+
+    /// Creates a new [`Grid2D`] if there are `minor` inner collections of
+    /// `major` items each, `None` otherwise.
+    pub fn new_mut(size: impl Into<Size>, items: T) -> Option<Self>
+    where
+        T: AsMut<[U]>,
+        U: AsMut<[I]>,
+    {
+        Self::try_new_mut(size, items).ok()
+    }
+
+    /// Creates a new [`Grid2D`] if there are `minor` inner collections of
+    /// `major` items each, a [`GridError`] otherwise.
+    ///
+    /// Returns [`GridError::LenMismatch`] if the outer collection has the
+    /// wrong length, [`GridError::LineLenMismatch`] for the first ragged
+    /// inner collection.
+    ///
+    /// ```
+    /// # use tender::grid::*;
+    /// let grid = RowVec2D::try_new((2, 2), vec![vec![1, 2], vec![3]]);
+    ///
+    /// assert_eq!(
+    ///     grid.unwrap_err(),
+    ///     GridError::LineLenMismatch { line: 1, len: 1, expected: 2 }
+    /// );
+    /// ```
+    pub fn try_new(size: impl Into<Size>, items: T) -> Result<Self, GridError>
+    where
+        T: AsRef<[U]>,
+        U: AsRef<[I]>,
+    {
+        let size = size.into();
+        check::<M>(size, items.as_ref().iter().map(|line| line.as_ref().len()))?;
+
+        Ok(Self::new_unchecked(size, items))
+    }
+
+    /// Creates a new [`Grid2D`] if there are `minor` inner collections of
+    /// `major` items each, a [`GridError`] otherwise.
+    ///
+    /// See [`Grid2D::try_new()`].
+    pub fn try_new_mut(size: impl Into<Size>, mut items: T) -> Result<Self, GridError>
+    where
+        T: AsMut<[U]>,
+        U: AsMut<[I]>,
+    {
+        let size = size.into();
+        check::<M>(size, items.as_mut().iter_mut().map(|line| line.as_mut().len()))?;
+
+        Ok(Self::new_unchecked(size, items))
+    }
+
+    /// Returns the underlying item collection.
+    pub fn into_inner(self) -> T {
+        self.items
+    }
+}
+
+/// Checks that the lengths of the inner collections match `size`.
+fn check<M: Major>(size: Size, lens: impl ExactSizeIterator<Item = usize>) -> Result<(), GridError> {
+    let major = M::from(size);
+
+    if lens.len() != major.minor() {
+        return Err(GridError::LenMismatch {
+            len: lens.len(),
+            size,
+        });
+    }
+
+    for (line, len) in lens.enumerate() {
+        if len != major.major() {
+            return Err(GridError::LineLenMismatch {
+                line,
+                len,
+                expected: major.major(),
+            });
+        }
+    }
+
+    Ok(())
 }
 
 impl<M, I, T: AsRef<[U]>, U> AsRef<[U]> for Grid2D<M, I, T, U> {
@@ -165,6 +255,7 @@ macro_rules! grid {
 
             // Minors
             grid!(impl [ITER] $Type $M $GridMinors $Minors $minors AsRef Index2D Minors);
+            grid!(impl [ITER] $Type $M $GridMinors $Minors $minors AsMut Index2D MinorsMut (mut));
 
             // Items
             grid!(impl [ITEMS] $Type $GridMajors $Majors $majors AsRef);
@@ -17,6 +17,31 @@ pub trait Index0D: Clone {
     /// - `point.x < size.x`
     /// - `point.y < size.y`
     fn checked(self, size: impl Into<Size>) -> Option<Point>;
+
+    /// Returns the index as a [`Point`], or a [`GridError`] if out of bounds.
+    ///
+    /// When `Ok`, guaranties:
+    /// - `point.x < size.x`
+    /// - `point.y < size.y`
+    fn try_checked(self, size: impl Into<Size>) -> Result<Point, GridError> {
+        let (point, size) = (self.unchecked(), size.into());
+
+        if point.x >= size.x {
+            Err(GridError::OutOfBounds {
+                axis:  Axis::X,
+                index: point.x,
+                len:   size.x,
+            })
+        } else if point.y >= size.y {
+            Err(GridError::OutOfBounds {
+                axis:  Axis::Y,
+                index: point.y,
+                len:   size.y,
+            })
+        } else {
+            Ok(point)
+        }
+    }
 }
 
 impl<T: Into<Point> + Clone> Index0D for T {
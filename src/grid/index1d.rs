@@ -1,5 +1,5 @@
 use crate::grid::*;
-use std::ops::Range;
+use std::ops::{Bound, Range};
 
 /// Indexes for [`GridCol::Col`]/[`GridRow::Row`].
 ///
@@ -29,6 +29,37 @@ pub trait Index1D: Clone + Sized {
     /// - `range.end <= end`
     fn checked(self, max_i: usize, max_end: usize) -> Option<(usize, Range<usize>)>;
 
+    /// Returns the index as `(usize, Range<usize>)`, or a [`GridError`] if out
+    /// of bounds.
+    ///
+    /// `axis` is the axis of `usize`, the range being on the other axis.
+    ///
+    /// When `Ok`, guaranties the same as [`checked()`](Index1D::checked).
+    ///
+    /// Defaults to [`checked()`](Index1D::checked), reporting the
+    /// [`unchecked()`](Index1D::unchecked) index on error.
+    fn try_checked(
+        self,
+        max_i: usize,
+        max_end: usize,
+        axis: Axis,
+    ) -> Result<(usize, Range<usize>), GridError> {
+        self.clone().checked(max_i, max_end).ok_or_else(|| {
+            let (i, range) = self.unchecked(max_end);
+
+            if i < max_i {
+                GridError::RangeOutOfBounds {
+                    axis:  other(axis),
+                    start: Bound::Included(range.start),
+                    end:   Bound::Excluded(range.end),
+                    len:   max_end,
+                }
+            } else {
+                out_of_bounds(i, max_i, axis)
+            }
+        })
+    }
+
     /// Returns the column index as `(usize, Range<usize>)`, without bounds
     /// checking.
     ///
@@ -56,6 +87,16 @@ pub trait Index1D: Clone + Sized {
         self.checked(size.x, size.y)
     }
 
+    /// Returns the column index as `(usize, Range<usize>)`, or a
+    /// [`GridError`] if out of bounds.
+    ///
+    /// When `Ok`, guaranties the same as [`col()`](Index1D::col).
+    fn try_col(self, size: impl Into<Size>) -> Result<(usize, Range<usize>), GridError> {
+        let size = size.into();
+
+        self.try_checked(size.x, size.y, Axis::X)
+    }
+
     /// Returns the row index as `(usize, Range<usize>)`, without bounds
     /// checking.
     ///
@@ -82,6 +123,27 @@ pub trait Index1D: Clone + Sized {
 
         self.checked(size.y, size.x)
     }
+
+    /// Returns the row index as `(usize, Range<usize>)`, or a [`GridError`]
+    /// if out of bounds.
+    ///
+    /// When `Ok`, guaranties the same as [`row()`](Index1D::row).
+    fn try_row(self, size: impl Into<Size>) -> Result<(usize, Range<usize>), GridError> {
+        let size = size.into();
+
+        self.try_checked(size.y, size.x, Axis::Y)
+    }
+}
+
+fn out_of_bounds(index: usize, len: usize, axis: Axis) -> GridError {
+    GridError::OutOfBounds { axis, index, len }
+}
+
+fn other(axis: Axis) -> Axis {
+    match axis {
+        Axis::X => Axis::Y,
+        Axis::Y => Axis::X,
+    }
 }
 
 impl Index1D for usize {
@@ -96,6 +158,19 @@ impl Index1D for usize {
             None
         }
     }
+
+    fn try_checked(
+        self,
+        max_i: usize,
+        max_end: usize,
+        axis: Axis,
+    ) -> Result<(usize, Range<usize>), GridError> {
+        if self < max_i {
+            Ok(self.unchecked(max_end))
+        } else {
+            Err(out_of_bounds(self, max_i, axis))
+        }
+    }
 }
 
 impl<T: ToRange + Clone> Index1D for (usize, T) {
@@ -112,4 +187,19 @@ impl<T: ToRange + Clone> Index1D for (usize, T) {
             None
         }
     }
+
+    fn try_checked(
+        self,
+        max_i: usize,
+        max_end: usize,
+        axis: Axis,
+    ) -> Result<(usize, Range<usize>), GridError> {
+        let (i, range) = self;
+
+        if i < max_i {
+            Ok((i, range.try_checked(max_end, other(axis))?))
+        } else {
+            Err(out_of_bounds(i, max_i, axis))
+        }
+    }
 }
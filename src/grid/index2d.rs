@@ -1,4 +1,5 @@
 use crate::grid::*;
+use std::ops::Bound;
 
 /// Indexes for [`GridCols::Cols`]/[`GridRows::Rows`]/[`GridItems::Items`].
 ///
@@ -26,6 +27,32 @@ pub trait Index2D: Clone {
     /// - `start <= end`
     /// - `end <= len`
     fn checked(self, size: impl Into<Size>) -> Option<Rect>;
+
+    /// Returns the index as [`Rect`], or a [`GridError`] if out of bounds.
+    ///
+    /// When `Ok`, guaranties the same as [`checked()`](Index2D::checked).
+    ///
+    /// Defaults to [`checked()`](Index2D::checked), reporting the
+    /// [`unchecked()`](Index2D::unchecked) ranges on error.
+    fn try_checked(self, size: impl Into<Size>) -> Result<Rect, GridError> {
+        let size = size.into();
+
+        self.clone().checked(size).ok_or_else(|| {
+            let Rect { x, y } = self.unchecked(size);
+            let (axis, range, len) = if x.start > x.end || x.end > size.x {
+                (Axis::X, x, size.x)
+            } else {
+                (Axis::Y, y, size.y)
+            };
+
+            GridError::RangeOutOfBounds {
+                axis,
+                start: Bound::Included(range.start),
+                end:   Bound::Excluded(range.end),
+                len,
+            }
+        })
+    }
 }
 
 impl Index2D for std::ops::RangeFull {
@@ -41,6 +68,10 @@ impl Index2D for std::ops::RangeFull {
     fn checked(self, size: impl Into<Size>) -> Option<Rect> {
         Some(Index2D::unchecked(self, size))
     }
+
+    fn try_checked(self, size: impl Into<Size>) -> Result<Rect, GridError> {
+        Ok(Index2D::unchecked(self, size))
+    }
 }
 
 impl<X: ToRange + Clone, Y: ToRange + Clone> Index2D for Coord<X, Y> {
@@ -51,6 +82,10 @@ impl<X: ToRange + Clone, Y: ToRange + Clone> Index2D for Coord<X, Y> {
     fn checked(self, size: impl Into<Size>) -> Option<Rect> {
         (self.x, self.y).checked(size)
     }
+
+    fn try_checked(self, size: impl Into<Size>) -> Result<Rect, GridError> {
+        (self.x, self.y).try_checked(size)
+    }
 }
 
 impl<X: ToRange + Clone, Y: ToRange + Clone> Index2D for (X, Y) {
@@ -71,4 +106,55 @@ impl<X: ToRange + Clone, Y: ToRange + Clone> Index2D for (X, Y) {
             y: ToRange::checked(self.1, size.y)?,
         })
     }
+
+    fn try_checked(self, size: impl Into<Size>) -> Result<Rect, GridError> {
+        let size = size.into();
+
+        Ok(Point {
+            x: ToRange::try_checked(self.0, size.x, Axis::X)?,
+            y: ToRange::try_checked(self.1, size.y, Axis::Y)?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+    use std::ops::Bound::*;
+
+    /// An index relying on the default `try_checked`.
+    #[derive(Clone)]
+    struct Rows(std::ops::Range<usize>);
+
+    impl Index2D for Rows {
+        fn unchecked(self, size: impl Into<Size>) -> Rect {
+            Point {
+                x: 0..size.into().x,
+                y: self.0,
+            }
+        }
+
+        fn checked(self, size: impl Into<Size>) -> Option<Rect> {
+            let size = size.into();
+
+            (self.0.start <= self.0.end && self.0.end <= size.y).then(|| self.unchecked(size))
+        }
+    }
+
+    #[test]
+    fn default_try_checked() {
+        let size = Size { x: 2, y: 3 };
+
+        assert_eq!(Rows(1..3).try_checked(size), Ok(Rect { x: 0..2, y: 1..3 }));
+        assert_eq!(
+            Rows(1..4).try_checked(size),
+            Err(GridError::RangeOutOfBounds {
+                axis:  Axis::Y,
+                start: Included(1),
+                end:   Excluded(4),
+                len:   3,
+            })
+        );
+    }
 }
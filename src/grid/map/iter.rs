@@ -1,6 +1,13 @@
-//! Iterators for [`Map`](super::Map)'s `Grid*` implementations.
+//! Iterators for [`Map`](super::Map)'s and
+//! [`MapWithPoint`](super::MapWithPoint)'s `Grid*` implementations.
 
-#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+use super::*;
+use std::{iter::FusedIterator, marker::PhantomData, ops::Range};
+
+type Item<T> = <T as Iterator>::Item;
+
+/// A 2D iterator mapping the items of [`Map`](super::Map)'s columns/rows.
+#[derive(Copy, Clone, Debug)]
 pub struct Iter2D<T, F> {
     iter: T,
     fun:  F,
@@ -12,16 +19,189 @@ impl<T, F> Iter2D<T, F> {
     }
 }
 
-type Item<T> = <T as Iterator>::Item;
-type Iter<T> = <<T as Iterator>::Item as IntoIterator>::IntoIter;
+impl<T: Iterator, F: FnMut(Item<Iter<T::Item>>) -> I + Clone, I> Iterator for Iter2D<T, F>
+where
+    T::Item: IntoIterator,
+{
+    type Item = std::iter::Map<Iter<T::Item>, F>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        Some(self.iter.next()?.into_iter().map(self.fun.clone()))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        Some(self.iter.nth(n)?.into_iter().map(self.fun.clone()))
+    }
+}
+
+impl<T: DoubleEndedIterator, F: FnMut(Item<Iter<T::Item>>) -> I + Clone, I> DoubleEndedIterator
+    for Iter2D<T, F>
+where
+    T::Item: IntoIterator,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        Some(self.iter.next_back()?.into_iter().map(self.fun.clone()))
+    }
+
+    fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+        Some(self.iter.nth_back(n)?.into_iter().map(self.fun.clone()))
+    }
+}
+
+impl<T: ExactSizeIterator, F> ExactSizeIterator for Iter2D<T, F> where Self: Iterator {}
+
+impl<T: FusedIterator, F> FusedIterator for Iter2D<T, F> where Self: Iterator {}
+
+/// A 1D iterator mapping the items of
+/// [`MapWithPoint`](super::MapWithPoint)'s column/row with their [`Point`].
+#[derive(Clone, Debug)]
+pub struct WithPoint1D<M, T, F> {
+    iter:     T,
+    fun:      F,
+    index:    (usize, Range<usize>),
+    _phantom: PhantomData<M>,
+}
+
+impl<M, T, F> WithPoint1D<M, T, F> {
+    pub(crate) fn new(iter: T, fun: F, index: (usize, Range<usize>)) -> Self {
+        Self {
+            iter,
+            fun,
+            index,
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<M: Major, T: Iterator, F: FnMut(Point, T::Item) -> I, I> Iterator for WithPoint1D<M, T, F> {
+    type Item = I;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let item = self.iter.next()?;
+        let (i, current) = (self.index.0, self.index.1.next()?);
+
+        Some((self.fun)(M::new(current, i).into(), item))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
 
-impl<T: Iterator, I> Iterator for Iter2D<T, fn(Item<Iter<T>>) -> I>
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        let item = self.iter.nth(n)?;
+        let (i, current) = (self.index.0, self.index.1.nth(n)?);
+
+        Some((self.fun)(M::new(current, i).into(), item))
+    }
+}
+
+impl<M: Major, T: DoubleEndedIterator, F: FnMut(Point, T::Item) -> I, I> DoubleEndedIterator
+    for WithPoint1D<M, T, F>
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let item = self.iter.next_back()?;
+        let (i, current) = (self.index.0, self.index.1.next_back()?);
+
+        Some((self.fun)(M::new(current, i).into(), item))
+    }
+
+    fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+        let item = self.iter.nth_back(n)?;
+        let (i, current) = (self.index.0, self.index.1.nth_back(n)?);
+
+        Some((self.fun)(M::new(current, i).into(), item))
+    }
+}
+
+impl<M, T: ExactSizeIterator, F> ExactSizeIterator for WithPoint1D<M, T, F> where Self: Iterator {}
+
+impl<M, T: FusedIterator, F> FusedIterator for WithPoint1D<M, T, F> where Self: Iterator {}
+
+/// A 2D iterator mapping the items of
+/// [`MapWithPoint`](super::MapWithPoint)'s columns/rows with their [`Point`].
+#[derive(Clone, Debug)]
+pub struct WithPoint2D<M, T, F> {
+    iter:     T,
+    fun:      F,
+    minor:    Range<usize>,
+    major:    Range<usize>,
+    _phantom: PhantomData<M>,
+}
+
+impl<M: Major, T, F> WithPoint2D<M, T, F> {
+    pub(crate) fn new(iter: T, fun: F, index: Rect) -> Self {
+        let start = M::from(index.start());
+        let end = M::from(index.end());
+
+        Self {
+            iter,
+            fun,
+            minor: start.minor()..end.minor(),
+            major: start.major()..end.major(),
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<M: Major, T: Iterator, F: FnMut(Point, Item<Iter<T::Item>>) -> I + Clone, I> Iterator
+    for WithPoint2D<M, T, F>
 where
     T::Item: IntoIterator,
 {
-    type Item = std::iter::Map<Iter<T>, fn(Item<Iter<T>>) -> I>;
+    type Item = WithPoint1D<M, Iter<T::Item>, F>;
 
     fn next(&mut self) -> Option<Self::Item> {
-        Some(self.iter.next()?.into_iter().map(self.fun))
+        let iter = self.iter.next()?.into_iter();
+        let i = self.minor.next()?;
+
+        Some(WithPoint1D::new(
+            iter,
+            self.fun.clone(),
+            (i, self.major.clone()),
+        ))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        let iter = self.iter.nth(n)?.into_iter();
+        let i = self.minor.nth(n)?;
+
+        Some(WithPoint1D::new(
+            iter,
+            self.fun.clone(),
+            (i, self.major.clone()),
+        ))
+    }
+}
+
+impl<M: Major, T: DoubleEndedIterator, F: FnMut(Point, Item<Iter<T::Item>>) -> I + Clone, I>
+    DoubleEndedIterator for WithPoint2D<M, T, F>
+where
+    T::Item: IntoIterator,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.nth_back(0)
+    }
+
+    fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+        let iter = self.iter.nth_back(n)?.into_iter();
+        let i = self.minor.nth_back(n)?;
+
+        Some(WithPoint1D::new(
+            iter,
+            self.fun.clone(),
+            (i, self.major.clone()),
+        ))
     }
 }
+
+impl<M, T: ExactSizeIterator, F> ExactSizeIterator for WithPoint2D<M, T, F> where Self: Iterator {}
+
+impl<M, T: FusedIterator, F> FusedIterator for WithPoint2D<M, T, F> where Self: Iterator {}
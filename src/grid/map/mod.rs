@@ -1,25 +1,34 @@
-//! A grids that maps elements.
+//! Grids that map elements.
 
 pub mod iter;
 
 use super::*;
+use std::iter::Flatten;
+
+type Iter<T> = <T as IntoIterator>::IntoIter;
+
+// ----------------------------------------------------------- //
+//                                                             //
+// *************************** MAP *************************** //
+//                                                             //
+// ----------------------------------------------------------- //
 
 /// A grid that maps elements with a function.
 ///
 /// See [`Grid::map()`].
-#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
-pub struct Map<T: Grid, I> {
+#[derive(Copy, Clone, Debug)]
+pub struct Map<T, F> {
     pub(crate) grid: T,
-    pub(crate) fun:  fn(T::Item) -> I,
+    pub(crate) fun:  F,
 }
 
-impl<T: Grid, I> WithSize for Map<T, I> {
+impl<T: WithSize, F> WithSize for Map<T, F> {
     fn size(&self) -> Size {
         self.grid.size()
     }
 }
 
-impl<T: Grid, I> Grid for Map<T, I> {
+impl<T: Grid, F: Fn(T::Item) -> I, I> Grid for Map<T, F> {
     type Item = I;
 
     unsafe fn item_unchecked(self, index: impl Index0D) -> Self::Item {
@@ -28,32 +37,28 @@ impl<T: Grid, I> Grid for Map<T, I> {
 }
 
 macro_rules! grid1d {
-        ($($Trait:ident $Assoc:ident $fn:ident)*) => { $(
-            impl<T: $Trait, I> $Trait for Map<T, I> {
-                type $Assoc =
-                    std::iter::Map<<T::$Assoc as IntoIterator>::IntoIter, fn(T::Item) -> I>;
-
-                unsafe fn $fn(self, index: impl Index1D) -> Self::$Assoc {
-                    self.grid.$fn(index).into_iter().map(self.fun)
-                }
+    ($($Trait:ident $Assoc:ident $fn:ident)*) => { $(
+        impl<T: $Trait, F: Fn(T::Item) -> I, I> $Trait for Map<T, F> {
+            type $Assoc = std::iter::Map<Iter<T::$Assoc>, F>;
+
+            unsafe fn $fn(self, index: impl Index1D) -> Self::$Assoc {
+                self.grid.$fn(index).into_iter().map(self.fun)
             }
-        )* };
-    }
+        }
+    )* };
+}
 
 macro_rules! grid2d {
-        ($($Trait:ident $Assoc:ident $fn:ident)*) => { $(
-            impl<T: $Trait, I> $Trait for Map<T, I> {
-                type $Assoc = iter::Iter2D<<T::$Assoc as IntoIterator>::IntoIter, fn(T::Item) -> I>;
-
-                unsafe fn $fn(self, index: impl Index2D) -> Self::$Assoc {
-                    Self::$Assoc::new(
-                        self.grid.$fn(index).into_iter(),
-                        self.fun,
-                    )
-                }
+    ($($Trait:ident $Assoc:ident $fn:ident)*) => { $(
+        impl<T: $Trait, F: Fn(T::Item) -> I + Clone, I> $Trait for Map<T, F> {
+            type $Assoc = iter::Iter2D<Iter<T::$Assoc>, F>;
+
+            unsafe fn $fn(self, index: impl Index2D) -> Self::$Assoc {
+                Self::$Assoc::new(self.grid.$fn(index).into_iter(), self.fun)
             }
-        )* };
-    }
+        }
+    )* };
+}
 
 grid1d!(
     GridCol Col col_unchecked
@@ -65,10 +70,157 @@ grid2d!(
     GridRows Rows rows_unchecked
 );
 
-impl<T: GridItems, I> GridItems for Map<T, I> {
-    type Items = std::iter::Map<<T::Items as IntoIterator>::IntoIter, fn(T::Item) -> I>;
+impl<T: GridItems, F: Fn(T::Item) -> I, I> GridItems for Map<T, F> {
+    type Items = std::iter::Map<Iter<T::Items>, F>;
 
     unsafe fn items_unchecked(self, index: impl Index2D) -> Self::Items {
         self.grid.items_unchecked(index).into_iter().map(self.fun)
     }
 }
+
+// ---------------------------------------------------------------------- //
+//                                                                        //
+// *************************** MAP WITH POINT *************************** //
+//                                                                        //
+// ---------------------------------------------------------------------- //
+
+/// A grid that maps elements and their [`Point`] with a function.
+///
+/// Since the order of the underlying [`GridItems::Items`] is not known,
+/// [`Items`](GridItems::Items) are yielded row by row, through
+/// [`GridRows`].
+///
+/// See [`Grid::map_with_point()`], [`Indexed`].
+#[derive(Copy, Clone, Debug)]
+pub struct MapWithPoint<T, F> {
+    pub(crate) grid: T,
+    pub(crate) fun:  F,
+}
+
+/// A grid that yields elements with their [`Point`].
+///
+/// See [`Grid::indexed()`].
+pub type Indexed<T> =
+    MapWithPoint<T, fn(Point, <T as Grid>::Item) -> (Point, <T as Grid>::Item)>;
+
+impl<T: WithSize, F> WithSize for MapWithPoint<T, F> {
+    fn size(&self) -> Size {
+        self.grid.size()
+    }
+}
+
+impl<T: Grid, F: Fn(Point, T::Item) -> I, I> Grid for MapWithPoint<T, F> {
+    type Item = I;
+
+    unsafe fn item_unchecked(self, index: impl Index0D) -> Self::Item {
+        let index = index.unchecked();
+
+        (self.fun)(index, self.grid.item_unchecked(index))
+    }
+}
+
+macro_rules! grid1d_with_point {
+    ($($Trait:ident<$M:ident> $Assoc:ident $fn:ident)*) => { $(
+        impl<T: $Trait, F: Fn(Point, T::Item) -> I, I> $Trait for MapWithPoint<T, F> {
+            type $Assoc = iter::WithPoint1D<$M, Iter<T::$Assoc>, F>;
+
+            unsafe fn $fn(self, index: impl Index1D) -> Self::$Assoc {
+                let index = index.$fn(self.size());
+
+                Self::$Assoc::new(self.grid.$fn(index.clone()).into_iter(), self.fun, index)
+            }
+        }
+    )* };
+}
+
+macro_rules! grid2d_with_point {
+    ($($Trait:ident<$M:ident> $Assoc:ident $fn:ident)*) => { $(
+        impl<T: $Trait, F: Fn(Point, T::Item) -> I + Clone, I> $Trait for MapWithPoint<T, F> {
+            type $Assoc = iter::WithPoint2D<$M, Iter<T::$Assoc>, F>;
+
+            unsafe fn $fn(self, index: impl Index2D) -> Self::$Assoc {
+                let index = index.unchecked(self.size());
+
+                Self::$Assoc::new(self.grid.$fn(index.clone()).into_iter(), self.fun, index)
+            }
+        }
+    )* };
+}
+
+grid1d_with_point!(
+    GridCol<ColMajor> Col col_unchecked
+    GridRow<RowMajor> Row row_unchecked
+);
+
+grid2d_with_point!(
+    GridCols<ColMajor> Cols cols_unchecked
+    GridRows<RowMajor> Rows rows_unchecked
+);
+
+impl<T: GridRows, F: Fn(Point, T::Item) -> I + Clone, I> GridItems for MapWithPoint<T, F> {
+    type Items = Flatten<<Self as GridRows>::Rows>;
+
+    unsafe fn items_unchecked(self, index: impl Index2D) -> Self::Items {
+        self.rows_unchecked(index).flatten()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    fn grid() -> RowVec1D<i32> {
+        RowVec1D::new((3, 3), (0..9).collect()).unwrap()
+    }
+
+    fn points<'a, T>(grid: T) -> Vec<(Point, i32)>
+    where
+        T: GridRows<Item = (Point, &'a i32)>,
+    {
+        grid.flatten_rows()
+            .map(|(point, &item)| (point, item))
+            .collect()
+    }
+
+    #[test]
+    fn indexed_crop() {
+        let grid = grid();
+        let p = |x, y| Point { x, y };
+
+        let indexed = (&grid).indexed().crop((1..3, 1..3)).unwrap();
+        assert_eq!(points(indexed), [(p(1, 1), 4), (p(2, 1), 5), (p(1, 2), 7), (p(2, 2), 8)]);
+
+        let cropped = (&grid).crop((1..3, 1..3)).unwrap().indexed();
+        assert_eq!(points(cropped), [(p(0, 0), 4), (p(1, 0), 5), (p(0, 1), 7), (p(1, 1), 8)]);
+
+        let indexed = (&grid).indexed().crop((1..3, 1..3)).unwrap();
+        let col = indexed.clone().col((1, 1..2)).unwrap().collect::<Vec<_>>();
+        assert_eq!(col, [(p(2, 2), &8)]);
+        assert_eq!(indexed.item((0, 1)), Some((p(1, 2), &7)));
+    }
+
+    #[test]
+    fn indexed_zip_at() {
+        let (grid, other) = (grid(), RowVec1D::new((2, 2), vec![10, 11, 12, 13]).unwrap());
+        let p = |x, y| Point { x, y };
+        let zipped = |position: (isize, isize)| {
+            (&grid)
+                .indexed()
+                .zip_at(position, &other)
+                .flatten_rows()
+                .map(|((point, &a), &b)| (point, a, b))
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(zipped((1, 1)), [
+            (p(1, 1), 4, 10),
+            (p(2, 1), 5, 11),
+            (p(1, 2), 7, 12),
+            (p(2, 2), 8, 13),
+        ]);
+        assert_eq!(zipped((-1, -1)), [(p(0, 0), 0, 13)]);
+        assert_eq!(zipped((2, -1)), [(p(2, 0), 2, 12)]);
+        assert_eq!(zipped((3, 0)), []);
+    }
+}
@@ -7,7 +7,7 @@
 //! iteration and integrates well with `std::iter`.
 //!
 //! This crate is very simple: all you can do is query items and iterate over
-//! columns or rows. You wont be able to add/remove columns/rows, for instance.
+//! columns or rows. Only owned [`Vec1D`] grids can add/remove columns/rows.
 //! Hence it is probably not very well suited for math/physics. Rust ecosystem
 //! already provides that anyway. I wrote this crate to power a
 //! layer-compositing lib for terminal rendering.
@@ -162,23 +162,49 @@
 // #![warn(missing_docs)]
 // - Implementors:
 //   - [`Grid1D`]
+//   - [`RingGrid`]
+//   - [`SparseGrid`]
 // - Adapters:
 //   - [`Cloned`], [`Copied`]
+//   - [`HCat`], [`VCat`]
 //   - [`Crop`]
+//   - [`Map`], [`MapWithPoint`], [`Indexed`]
+//   - [`Windows`], [`Neighbors`]
+//   - [`Chunks`], [`Tiles`]
+//   - [`FlipX`], [`FlipY`]
+//   - [`Pretty`]
 //   - [`Repeat`], [`RepeatWith`]
-//   - [`Zip`]
+//   - [`Scale`], [`Downscale`]
+//   - [`Transpose`]
+//   - [`Traverse`], [`Rings`], [`Diagonals`]
+//   - [`Zip`], [`ZipLongest`]
 
 // pub and re-exported
 pub mod cloned;
+pub mod cat;
 pub mod crop;
+pub mod diff;
+pub mod flip;
 pub mod grid1d;
 pub mod grid2d;
 pub mod map;
+pub mod neighborhood;
+pub mod pretty;
 pub mod repeat;
+pub mod ring;
+pub mod scale;
+pub mod sparse;
+pub mod tiles;
+pub mod transpose;
+pub mod traverse;
 pub mod zip;
+pub mod zip_longest;
 
+pub use cat::{HCat, HCatFill, VCat, VCatFill};
 pub use cloned::{Cloned, Copied};
 pub use crop::Crop;
+pub use diff::{diff, Diff};
+pub use flip::{FlipX, FlipY, Rotate180, Rotate270, Rotate90};
 pub use grid1d::{
     Array1D,
     ColArray1D,
@@ -204,11 +230,22 @@ pub use grid2d::{
     RowVec2D,
     Vec2D,
 };
-pub use map::Map;
+pub use map::{Indexed, Map, MapWithPoint};
+pub use neighborhood::{Edge, Neighborhood, Neighbors, Windows};
+pub use pretty::Pretty;
 pub use repeat::{repeat, repeat_with, Repeat, RepeatWith};
+pub use ring::RingGrid;
+pub use scale::{Downscale, Scale};
+pub use sparse::SparseGrid;
+pub use tiles::{Chunks, Tiles};
+pub use transpose::Transpose;
+pub use traverse::{Border, Diagonal, Diagonals, Morton, Rings, Spiral, Traverse};
 pub use zip::Zip;
+pub use zip_longest::ZipLongest;
 
 // re-exported
+mod error;
+mod from_grid;
 mod grid;
 mod index0d;
 mod index1d;
@@ -217,6 +254,8 @@ mod major;
 mod to_range;
 mod with_size;
 
+pub use error::*;
+pub use from_grid::*;
 pub use self::grid::*;
 pub use index0d::*;
 pub use index1d::*;
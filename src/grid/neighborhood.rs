@@ -0,0 +1,411 @@
+//! Iterators over the neighborhoods of a grid.
+
+use crate::grid::*;
+use std::collections::VecDeque;
+
+// ------------------------------------------------------------------- //
+//                                                                     //
+// *************************** WINDOWS ******************************* //
+//                                                                     //
+// ------------------------------------------------------------------- //
+
+/// An iterator over overlapping [`Crop`]s of a grid.
+///
+/// Windows are yielded row by row.
+///
+/// See [`Grid::windows()`].
+#[derive(Clone, Debug)]
+pub struct Windows<T> {
+    grid:  T,
+    size:  Size,
+    end:   Point,
+    point: Point,
+}
+
+impl<T: WithSize> Windows<T> {
+    pub(crate) fn new(grid: T, size: Size) -> Self {
+        assert!(size.x != 0 && size.y != 0, "Window size is zero");
+
+        let grid_size = grid.size();
+        let end = if size.x <= grid_size.x && size.y <= grid_size.y {
+            grid_size - size + Point::ONE
+        } else {
+            Point::ZERO
+        };
+
+        Self {
+            grid,
+            size,
+            end,
+            point: Point::ZERO,
+        }
+    }
+}
+
+impl<T: Grid + Clone> Iterator for Windows<T> {
+    type Item = Crop<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.point.y >= self.end.y {
+            return None;
+        }
+
+        let rect = self.point.rect(self.size);
+
+        self.point.x += 1;
+        if self.point.x == self.end.x {
+            self.point.x = 0;
+            self.point.y += 1;
+        }
+
+        // SAFETY: windows start before `grid.size() - size`
+        debug_assert!(rect.clone().checked(self.grid.size()).is_some());
+        Some(unsafe { self.grid.clone().crop_unchecked(rect) })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = if self.point.y >= self.end.y {
+            0
+        } else {
+            (self.end.y - self.point.y) * self.end.x - self.point.x
+        };
+
+        (len, Some(len))
+    }
+}
+
+impl<T: Grid + Clone> ExactSizeIterator for Windows<T> {}
+
+// ------------------------------------------------------------------- //
+//                                                                     //
+// *************************** NEIGHBORS ***************************** //
+//                                                                     //
+// ------------------------------------------------------------------- //
+
+/// The cells considered adjacent to a [`Point`].
+///
+/// See [`Grid::neighbors()`].
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum Neighborhood {
+    /// The 8 surrounding cells, diagonals included.
+    Moore,
+    /// The 4 orthogonally adjacent cells.
+    VonNeumann,
+}
+
+impl Neighborhood {
+    pub(crate) fn offsets(self) -> &'static [(isize, isize)] {
+        match self {
+            Self::Moore => &[
+                (-1, -1),
+                (0, -1),
+                (1, -1),
+                (-1, 0),
+                (1, 0),
+                (-1, 1),
+                (0, 1),
+                (1, 1),
+            ],
+            Self::VonNeumann => &[(0, -1), (-1, 0), (1, 0), (0, 1)],
+        }
+    }
+}
+
+/// How neighbors outside of the grid are handled.
+///
+/// See [`Grid::neighbors()`].
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum Edge {
+    /// Yields the nearest cell on the edge of the grid.
+    Clamp,
+    /// Yields the cell on the opposite edge of the grid.
+    Wrap,
+    /// Yields nothing.
+    Skip,
+}
+
+impl Edge {
+    fn resolve(self, i: usize, offset: isize, len: usize) -> Option<usize> {
+        let i = i as isize + offset;
+        let len = len as isize;
+
+        match self {
+            Self::Clamp => Some(i.max(0).min(len - 1) as usize),
+            Self::Wrap => Some(i.rem_euclid(len) as usize),
+            Self::Skip if i < 0 || i >= len => None,
+            Self::Skip => Some(i as usize),
+        }
+    }
+}
+
+/// An iterator over the neighbors of a [`Point`], with their [`Point`].
+///
+/// Neighbors are yielded row by row.
+///
+/// See [`Grid::neighbors()`].
+#[derive(Clone, Debug)]
+pub struct Neighbors<T> {
+    grid:    T,
+    point:   Point,
+    edge:    Edge,
+    offsets: std::slice::Iter<'static, (isize, isize)>,
+}
+
+impl<T: WithSize> Neighbors<T> {
+    pub(crate) fn new(
+        grid: T,
+        point: impl Index0D,
+        neighborhood: Neighborhood,
+        edge: Edge,
+    ) -> Option<Self> {
+        let point = point.checked(grid.size())?;
+
+        Some(Self {
+            grid,
+            point,
+            edge,
+            offsets: neighborhood.offsets().iter(),
+        })
+    }
+}
+
+impl<T: Grid + Clone> Iterator for Neighbors<T> {
+    type Item = (Point, T::Item);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let size = self.grid.size();
+
+        for &(x, y) in &mut self.offsets {
+            let x = self.edge.resolve(self.point.x, x, size.x);
+            let y = self.edge.resolve(self.point.y, y, size.y);
+
+            if let (Some(x), Some(y)) = (x, y) {
+                let point = Point { x, y };
+
+                // SAFETY: edges are resolved within the grid
+                debug_assert!(point.checked(size).is_some());
+                return Some((point, unsafe { self.grid.clone().item_unchecked(point) }));
+            }
+        }
+
+        None
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.offsets.len();
+
+        match self.edge {
+            Edge::Skip => (0, Some(len)),
+            _ => (len, Some(len)),
+        }
+    }
+}
+
+// ------------------------------------------------------------------- //
+//                                                                     //
+// *************************** REGIONS ******************************* //
+//                                                                     //
+// ------------------------------------------------------------------- //
+
+/// Fills the region of `items` (row-major, of `size`) connected to `start`
+/// whose items satisfy `predicate`, returning the number of filled items.
+pub(crate) fn flood_fill<T: Clone>(
+    mut items: Vec<&mut T>,
+    size: Size,
+    start: Point,
+    neighborhood: Neighborhood,
+    mut predicate: impl FnMut(&T) -> bool,
+    value: T,
+) -> usize {
+    let start = start.y * size.x + start.x;
+    if !predicate(items[start]) {
+        return 0;
+    }
+
+    let mut visited = vec![false; items.len()];
+    let mut queue = VecDeque::from(vec![start]);
+    let mut filled = 0;
+    visited[start] = true;
+
+    while let Some(i) = queue.pop_front() {
+        *items[i] = value.clone();
+        filled += 1;
+
+        for j in adjacent(i, size, neighborhood) {
+            if !visited[j] && predicate(items[j]) {
+                visited[j] = true;
+                queue.push_back(j);
+            }
+        }
+    }
+
+    filled
+}
+
+/// Labels the connected regions of `items` (row-major, of `size`) whose
+/// adjacent items are `eq`, returning the labels and their bounding rects.
+pub(crate) fn connected_components<T>(
+    items: Vec<T>,
+    size: Size,
+    neighborhood: Neighborhood,
+    mut eq: impl FnMut(&T, &T) -> bool,
+) -> (RowVec1D<usize>, Vec<Rect>) {
+    let mut labels = vec![usize::MAX; items.len()];
+    let mut rects = Vec::<Rect>::new();
+    let mut queue = VecDeque::new();
+
+    for start in 0..items.len() {
+        if labels[start] != usize::MAX {
+            continue;
+        }
+
+        let label = rects.len();
+        let mut rect = point(start, size).rect(Size::ONE);
+        labels[start] = label;
+        queue.push_back(start);
+
+        while let Some(i) = queue.pop_front() {
+            rect = rect.union(&point(i, size).rect(Size::ONE));
+
+            for j in adjacent(i, size, neighborhood) {
+                if labels[j] == usize::MAX && eq(&items[i], &items[j]) {
+                    labels[j] = label;
+                    queue.push_back(j);
+                }
+            }
+        }
+
+        rects.push(rect);
+    }
+
+    let labels = RowVec1D::new(size, labels)
+        .expect("Grid yields a different number of items than its size");
+
+    (labels, rects)
+}
+
+fn point(i: usize, size: Size) -> Point {
+    Point {
+        x: i % size.x,
+        y: i / size.x,
+    }
+}
+
+/// Returns the row-major indexes of the in-bounds neighbors of `i`.
+fn adjacent(i: usize, size: Size, neighborhood: Neighborhood) -> impl Iterator<Item = usize> {
+    let Point { x, y } = point(i, size);
+
+    neighborhood.offsets().iter().filter_map(move |&(dx, dy)| {
+        let x = Edge::Skip.resolve(x, dx, size.x)?;
+        let y = Edge::Skip.resolve(y, dy, size.y)?;
+
+        Some(y * size.x + x)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[rustfmt::skip]
+    fn checkerboard() -> RowVec1D<u8> {
+        RowVec1D::new((3, 3), vec![
+            1, 0, 1,
+            0, 1, 0,
+            1, 0, 0,
+        ]).unwrap()
+    }
+
+    fn neighbors(point: (usize, usize), edge: Edge) -> Vec<usize> {
+        let grid = RowVec1D::new((3, 3), (0..9).collect()).unwrap();
+        let neighbors = (&grid).neighbors(point, Neighborhood::Moore, edge).unwrap();
+
+        neighbors.map(|(_, &item)| item).collect()
+    }
+
+    #[test]
+    fn neighbors_top_left() {
+        assert_eq!(neighbors((0, 0), Edge::Clamp), [0, 0, 1, 0, 1, 3, 3, 4]);
+        assert_eq!(neighbors((0, 0), Edge::Wrap), [8, 6, 7, 2, 1, 5, 3, 4]);
+        assert_eq!(neighbors((0, 0), Edge::Skip), [1, 3, 4]);
+    }
+
+    #[test]
+    fn neighbors_bottom_right() {
+        assert_eq!(neighbors((2, 2), Edge::Clamp), [4, 5, 5, 7, 8, 7, 8, 8]);
+        assert_eq!(neighbors((2, 2), Edge::Wrap), [4, 5, 3, 7, 6, 1, 2, 0]);
+        assert_eq!(neighbors((2, 2), Edge::Skip), [4, 5, 7]);
+    }
+
+    #[test]
+    fn neighbors_points_and_bounds() {
+        let grid = RowVec1D::new((1, 1), vec![7]).unwrap();
+
+        for edge in [Edge::Clamp, Edge::Wrap] {
+            let neighbors = (&grid).neighbors((0, 0), Neighborhood::VonNeumann, edge);
+            let neighbors = neighbors.unwrap().collect::<Vec<_>>();
+            assert_eq!(neighbors, [(Point::ZERO, &7); 4]);
+        }
+
+        let mut neighbors = (&grid)
+            .neighbors((0, 0), Neighborhood::Moore, Edge::Skip)
+            .unwrap();
+        assert_eq!(neighbors.size_hint(), (0, Some(8)));
+        assert_eq!(neighbors.next(), None);
+
+        assert!((&grid)
+            .neighbors((1, 0), Neighborhood::Moore, Edge::Clamp)
+            .is_none());
+    }
+
+    #[test]
+    fn flood_fill_moore() {
+        let mut grid = checkerboard();
+        let filled = (&mut grid).flood_fill((0, 0), Neighborhood::Moore, |&item| item == 1, 2);
+
+        assert_eq!(filled, Some(4));
+        assert_eq!(grid.as_ref(), &[2, 0, 2, 0, 2, 0, 2, 0, 0]);
+    }
+
+    #[test]
+    fn flood_fill_von_neumann() {
+        let mut grid = checkerboard();
+        let filled = (&mut grid).flood_fill((0, 0), Neighborhood::VonNeumann, |&item| item == 1, 2);
+
+        assert_eq!(filled, Some(1));
+        assert_eq!(grid.as_ref(), &[2, 0, 1, 0, 1, 0, 1, 0, 0]);
+    }
+
+    #[test]
+    fn flood_fill_start_fails_predicate() {
+        let mut grid = checkerboard();
+        let filled = (&mut grid).flood_fill((1, 0), Neighborhood::Moore, |&item| item == 1, 2);
+
+        assert_eq!(filled, Some(0));
+        assert_eq!(grid, checkerboard());
+    }
+
+    #[test]
+    fn flood_fill_out_of_bounds() {
+        let mut grid = checkerboard();
+
+        assert_eq!((&mut grid).flood_fill((3, 0), Neighborhood::Moore, |_| true, 2), None);
+    }
+
+    #[test]
+    fn connected_components_moore() {
+        let grid = checkerboard();
+        let (labels, rects) = (&grid).connected_components(Neighborhood::Moore, |a, b| a == b);
+
+        assert_eq!(labels.as_ref(), &[0, 1, 0, 1, 0, 1, 0, 1, 1]);
+        assert_eq!(rects, [
+            Rect { x: 0..3, y: 0..3 },
+            Rect { x: 0..3, y: 0..3 },
+        ]);
+
+        let (labels, rects) = (&grid).connected_components(Neighborhood::VonNeumann, |a, b| a == b);
+        assert_eq!(labels.as_ref(), &[0, 1, 2, 3, 4, 5, 6, 5, 5]);
+        assert_eq!(rects[5], Rect { x: 1..3, y: 1..3 });
+    }
+}
@@ -0,0 +1,136 @@
+//! Pretty printing of grids.
+
+use crate::grid::*;
+use std::fmt::{self, Debug, Display, Formatter};
+
+/// A grid formatted as an aligned 2D table.
+///
+/// Items are right-aligned in their column and separated by a space. Rows
+/// and columns beyond the [`limit()`](Pretty::limit) are shown as `…`.
+///
+/// Implements [`Display`] when the grid's items implement [`Display`], and
+/// [`Debug`] when they implement [`Debug`].
+///
+/// See [`Grid::pretty()`].
+///
+/// # Example
+///
+/// ```
+/// # use tender::grid::*;
+/// let grid = RowVec1D::new((3, 2), vec![1, 20, 3, 4, 5, 600]).unwrap();
+///
+/// assert_eq!(grid.pretty().to_string(), "1 20   3\n4  5 600");
+/// assert_eq!(
+///     grid.pretty().indices().limit((2, 1)).to_string(),
+///     "  0  1\n0 1 20 …\n  …  …"
+/// );
+/// ```
+#[derive(Copy, Clone, Default)]
+pub struct Pretty<T> {
+    grid:    T,
+    indices: bool,
+    limit:   Option<Size>,
+}
+
+impl<T> Pretty<T> {
+    pub(crate) fn new(grid: T) -> Self {
+        Self {
+            grid,
+            indices: false,
+            limit: None,
+        }
+    }
+
+    /// Prints row indices on the left and column indices on top.
+    pub fn indices(mut self) -> Self {
+        self.indices = true;
+        self
+    }
+
+    /// Prints at most `limit.x` columns and `limit.y` rows.
+    pub fn limit(mut self, limit: impl Into<Size>) -> Self {
+        self.limit = Some(limit.into());
+        self
+    }
+}
+
+impl<T: GridRows + Clone> Pretty<T> {
+    fn table(&self, f: &mut Formatter, item: impl Fn(T::Item) -> String) -> fmt::Result {
+        let size = self.grid.size();
+        let shown = self.limit.map_or(size, |limit| limit.min(size));
+        let (more_x, more_y) = (shown.x < size.x, shown.y < size.y);
+
+        // SAFETY: shown <= size
+        let cells = unsafe { self.grid.clone().rows_unchecked((..shown.x, ..shown.y)) }
+            .into_iter()
+            .map(|row| row.into_iter().map(&item).collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+
+        let mut widths = vec![1; shown.x];
+        for row in &cells {
+            for (width, cell) in widths.iter_mut().zip(row) {
+                *width = (*width).max(cell.chars().count());
+            }
+        }
+        if self.indices {
+            for (x, width) in widths.iter_mut().enumerate() {
+                *width = (*width).max(digits(x));
+            }
+        }
+        let index_width = digits(shown.y.saturating_sub(1));
+
+        let mut lines = Vec::with_capacity(shown.y + 2);
+        if self.indices && shown.x != 0 {
+            lines.push((None, (0..shown.x).map(|x| x.to_string()).collect(), false));
+        }
+        for (y, row) in cells.into_iter().enumerate() {
+            lines.push((Some(y.to_string()), row, more_x));
+        }
+        if more_y {
+            lines.push((Some(String::new()), vec![String::from("…"); shown.x], false));
+        }
+
+        for (i, (index, row, more)) in lines.into_iter().enumerate() {
+            if i != 0 {
+                f.write_str("\n")?;
+            }
+            if self.indices {
+                write!(f, "{:>1$} ", index.unwrap_or_default(), index_width)?;
+            }
+            for (x, (cell, width)) in row.iter().zip(&widths).enumerate() {
+                if x != 0 {
+                    f.write_str(" ")?;
+                }
+                write!(f, "{:>1$}", cell, width)?;
+            }
+            if more {
+                f.write_str(" …")?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl<T: GridRows + Clone> Display for Pretty<T>
+where
+    T::Item: Display,
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        self.table(f, |item| item.to_string())
+    }
+}
+
+impl<T: GridRows + Clone> Debug for Pretty<T>
+where
+    T::Item: Debug,
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        self.table(f, |item| format!("{:?}", item))
+    }
+}
+
+/// Returns the number of decimal digits of `n`.
+fn digits(n: usize) -> usize {
+    n.checked_ilog10().map_or(1, |log| log as usize + 1)
+}
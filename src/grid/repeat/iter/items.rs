@@ -1,57 +1,84 @@
 use super::*;
-use std::ops::Range;
+use std::iter::FusedIterator;
 
-#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
-pub struct Items<I> {
-    fun:    fn(Point) -> I,
+#[derive(Copy, Clone, Debug)]
+pub struct Items<F> {
+    fun:    F,
     xstart: usize,
-    xend:   usize,
-    yend:   usize,
-    x:      usize,
-    y:      usize,
+    ystart: usize,
+    width:  usize,
+    /// Row-major index of the next item.
+    front:  usize,
+    /// Row-major index after the last item.
+    back:   usize,
 }
 
-impl<I> Items<I> {
-    pub(crate) fn new(fun: fn(Point) -> I, index: Rect) -> Self {
-        let Point {
-            x: Range {
-                start: x,
-                end: xend,
-            },
-            y: Range {
-                start: y,
-                end: yend,
-            },
-        } = index;
+impl<F> Items<F> {
+    pub(crate) fn new(fun: F, index: Rect) -> Self {
+        let Size { x: width, y: height } = index.size();
 
         Self {
             fun,
-            xstart: x,
-            xend,
-            yend,
-            x,
-            y,
+            xstart: index.x.start,
+            ystart: index.y.start,
+            width,
+            front: 0,
+            back: width * height,
+        }
+    }
+
+    fn point(&self, i: usize) -> Point {
+        Point {
+            x: self.xstart + i % self.width,
+            y: self.ystart + i / self.width,
         }
     }
 }
 
-impl<I> Iterator for Items<I> {
+impl<F: FnMut(Point) -> I, I> Iterator for Items<F> {
     type Item = I;
 
     fn next(&mut self) -> Option<Self::Item> {
-        let (x, y) = (self.x, self.y);
-
-        let (x, y) = if x < self.xend {
-            self.x += 1;
-            (x, y)
-        } else if y < self.yend {
-            self.x = self.xstart;
-            self.y += 1;
-            (x, y)
-        } else {
+        self.nth(0)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.back - self.front;
+
+        (len, Some(len))
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        if n >= self.back - self.front {
+            self.front = self.back;
             return None;
-        };
+        }
 
-        Some((self.fun)(Point { x, y }))
+        let point = self.point(self.front + n);
+        self.front += n + 1;
+
+        Some((self.fun)(point))
     }
 }
+
+impl<F: FnMut(Point) -> I, I> DoubleEndedIterator for Items<F> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.nth_back(0)
+    }
+
+    fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+        if n >= self.back - self.front {
+            self.back = self.front;
+            return None;
+        }
+
+        self.back -= n + 1;
+        let point = self.point(self.back);
+
+        Some((self.fun)(point))
+    }
+}
+
+impl<F: FnMut(Point) -> I, I> ExactSizeIterator for Items<F> {}
+
+impl<F: FnMut(Point) -> I, I> FusedIterator for Items<F> {}
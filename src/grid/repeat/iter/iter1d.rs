@@ -1,15 +1,15 @@
 use super::*;
-use std::{marker::PhantomData, ops::Range};
+use std::{iter::FusedIterator, marker::PhantomData, ops::Range};
 
-#[derive(Clone, Eq, PartialEq, Hash, Debug)]
-pub struct Iter1D<M, I> {
-    fun:      fn(Point) -> I,
+#[derive(Clone, Debug)]
+pub struct Iter1D<M, F> {
+    fun:      F,
     index:    (usize, Range<usize>),
     _phantom: PhantomData<M>,
 }
 
-impl<M, I> Iter1D<M, I> {
-    pub(crate) fn new(fun: fn(Point) -> I, index: (usize, Range<usize>)) -> Self {
+impl<M, F> Iter1D<M, F> {
+    pub(crate) fn new(fun: F, index: (usize, Range<usize>)) -> Self {
         Self {
             fun,
             index,
@@ -18,12 +18,36 @@ impl<M, I> Iter1D<M, I> {
     }
 }
 
-impl<M: Major, I> Iterator for Iter1D<M, I> {
+impl<M: Major, F: FnMut(Point) -> I, I> Iterator for Iter1D<M, F> {
     type Item = I;
 
     fn next(&mut self) -> Option<Self::Item> {
-        let (i, current) = (self.index.0, self.index.1.next()?);
+        self.nth(0)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.index.1.size_hint()
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        let (i, current) = (self.index.0, self.index.1.nth(n)?);
 
         Some((self.fun)(M::new(current, i).into()))
     }
 }
+
+impl<M: Major, F: FnMut(Point) -> I, I> DoubleEndedIterator for Iter1D<M, F> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.nth_back(0)
+    }
+
+    fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+        let (i, current) = (self.index.0, self.index.1.nth_back(n)?);
+
+        Some((self.fun)(M::new(current, i).into()))
+    }
+}
+
+impl<M: Major, F: FnMut(Point) -> I, I> ExactSizeIterator for Iter1D<M, F> {}
+
+impl<M: Major, F: FnMut(Point) -> I, I> FusedIterator for Iter1D<M, F> {}
@@ -1,15 +1,15 @@
 use super::*;
-use std::marker::PhantomData;
+use std::{iter::FusedIterator, marker::PhantomData};
 
-#[derive(Clone, Eq, PartialEq, Hash, Debug)]
-pub struct Iter2D<M, I> {
-    fun:      fn(Point) -> I,
+#[derive(Clone, Debug)]
+pub struct Iter2D<M, F> {
+    fun:      F,
     index:    Rect,
     _phantom: PhantomData<M>,
 }
 
-impl<M, I> Iter2D<M, I> {
-    pub(crate) fn new(fun: fn(Point) -> I, index: Rect) -> Self {
+impl<M, F> Iter2D<M, F> {
+    pub(crate) fn new(fun: F, index: Rect) -> Self {
         Self {
             fun,
             index,
@@ -18,24 +18,70 @@ impl<M, I> Iter2D<M, I> {
     }
 }
 
-impl<I> Iterator for Iter2D<RowMajor, I> {
-    type Item = super::Iter1D<RowMajor, I>;
+impl<F: FnMut(Point) -> I + Clone, I> Iterator for Iter2D<RowMajor, F> {
+    type Item = super::Iter1D<RowMajor, F>;
 
     fn next(&mut self) -> Option<Self::Item> {
+        self.nth(0)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.index.y.size_hint()
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        Some(Self::Item::new(
+            self.fun.clone(),
+            (self.index.y.nth(n)?, self.index.x.clone()),
+        ))
+    }
+}
+
+impl<F: FnMut(Point) -> I + Clone, I> DoubleEndedIterator for Iter2D<RowMajor, F> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.nth_back(0)
+    }
+
+    fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
         Some(Self::Item::new(
-            self.fun,
-            (self.index.y.next()?, self.index.x.clone()),
+            self.fun.clone(),
+            (self.index.y.nth_back(n)?, self.index.x.clone()),
         ))
     }
 }
 
-impl<I> Iterator for Iter2D<ColMajor, I> {
-    type Item = super::Iter1D<ColMajor, I>;
+impl<F: FnMut(Point) -> I + Clone, I> Iterator for Iter2D<ColMajor, F> {
+    type Item = super::Iter1D<ColMajor, F>;
 
     fn next(&mut self) -> Option<Self::Item> {
+        self.nth(0)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.index.x.size_hint()
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        Some(Self::Item::new(
+            self.fun.clone(),
+            (self.index.x.nth(n)?, self.index.y.clone()),
+        ))
+    }
+}
+
+impl<F: FnMut(Point) -> I + Clone, I> DoubleEndedIterator for Iter2D<ColMajor, F> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.nth_back(0)
+    }
+
+    fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
         Some(Self::Item::new(
-            self.fun,
-            (self.index.x.next()?, self.index.y.clone()),
+            self.fun.clone(),
+            (self.index.x.nth_back(n)?, self.index.y.clone()),
         ))
     }
 }
+
+impl<M, F> ExactSizeIterator for Iter2D<M, F> where Self: Iterator {}
+
+impl<M, F> FusedIterator for Iter2D<M, F> where Self: Iterator {}
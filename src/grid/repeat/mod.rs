@@ -151,7 +151,8 @@ repeat_grid!([ITEMS] self
 /// ```
 /// # use tender::geometry::*;
 /// # use tender::grid::*;
-/// let mut rows = repeat_with((2, 2), |Point { x, y }| if x == y { 1 } else { 0 })
+/// let diagonal = 1;
+/// let mut rows = repeat_with((2, 2), |Point { x, y }| if x == y { diagonal } else { 0 })
 ///     .rows((.., ..))
 ///     .unwrap();
 ///
@@ -165,9 +166,9 @@ repeat_grid!([ITEMS] self
 /// assert_eq!(row2.next(), Some(1));
 /// assert_eq!(row2.next(), None);
 ///
-/// assert_eq!(rows.next(), None);
+/// assert!(rows.next().is_none());
 /// ```
-pub fn repeat_with<I>(size: impl Into<Size>, fun: fn(Point) -> I) -> RepeatWith<I> {
+pub fn repeat_with<F: Fn(Point) -> I, I>(size: impl Into<Size>, fun: F) -> RepeatWith<F> {
     RepeatWith {
         size: size.into(),
         fun,
@@ -177,13 +178,13 @@ pub fn repeat_with<I>(size: impl Into<Size>, fun: fn(Point) -> I) -> RepeatWith<
 /// A grid that repeats elements by applying the provided function.
 ///
 /// See [`repeat_with()`], [`repeat()`].
-#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
-pub struct RepeatWith<I> {
+#[derive(Copy, Clone, Debug)]
+pub struct RepeatWith<F> {
     size: Size,
-    fun:  fn(Point) -> I,
+    fun:  F,
 }
 
-impl<I> WithSize for RepeatWith<I> {
+impl<F> WithSize for RepeatWith<F> {
     fn size(&self) -> Size {
         self.size
     }
@@ -193,7 +194,7 @@ macro_rules! repeat_with_grid {
     ([0D] $(
         $($lifetime:lifetime)?, $Type:ty,
     )*) => { $(
-        impl<$($lifetime,)? I> Grid for $Type {
+        impl<$($lifetime,)? F: Fn(Point) -> I, I> Grid for $Type {
             type Item = I;
 
             unsafe fn item_unchecked(self, index: impl Index0D) -> Self::Item {
@@ -201,56 +202,56 @@ macro_rules! repeat_with_grid {
             }
         }
     )* };
-    ([1D] $(
+    ([1D] $self:ident $(
         $Trait:ident<$M:ident> $Assoc:ident $fn:ident:
-        $($lifetime:lifetime)?, $Type:ty,
+        $($lifetime:lifetime)?, $Type:ty, $Fun:ty, $fun:expr,
     )*) => { $(
-        impl<$($lifetime,)? I> $Trait for $Type {
-            type $Assoc = iter::Iter1D<$M, I>;
+        impl<$($lifetime,)? F: Fn(Point) -> I, I> $Trait for $Type {
+            type $Assoc = iter::Iter1D<$M, $Fun>;
 
-            unsafe fn $fn(self, index: impl Index1D) -> Self::$Assoc {
-                Self::$Assoc::new(self.fun, index.$fn(self.size))
+            unsafe fn $fn($self, index: impl Index1D) -> Self::$Assoc {
+                Self::$Assoc::new($fun, index.$fn($self.size))
             }
         }
     )* };
-    ([2D] $(
-        $Trait:ident $Index:ident $Assoc:ident $fn:ident $Iter:ty:
-        $($lifetime:lifetime)?, $Type:ty,
+    ([2D] $self:ident $(
+        $Trait:ident $Index:ident $Assoc:ident $fn:ident $Iter:ident$(<$M:ident>)?:
+        $($lifetime:lifetime)?, $Type:ty, $Fun:ty, $fun:expr, $($Clone:ident)?,
     )*) => { $(
-        impl<$($lifetime,)? I> $Trait for $Type {
-            type $Assoc = $Iter;
+        impl<$($lifetime,)? F: Fn(Point) -> I $(+ $Clone)?, I> $Trait for $Type {
+            type $Assoc = $Iter<$($M,)? $Fun>;
 
-            unsafe fn $fn(self, index: impl $Index) -> Self::$Assoc {
-                Self::$Assoc::new(self.fun, index.unchecked(self.size))
+            unsafe fn $fn($self, index: impl $Index) -> Self::$Assoc {
+                Self::$Assoc::new($fun, index.unchecked($self.size))
             }
         }
     )* };
 }
 
 repeat_with_grid!([0D]
-      ,         RepeatWith<I>,
-    'a, &'a     RepeatWith<I>,
-    'a, &'a mut RepeatWith<I>,
+      ,         RepeatWith<F>,
+    'a, &'a     RepeatWith<F>,
+    'a, &'a mut RepeatWith<F>,
 );
-repeat_with_grid!([1D]
-    GridCol<ColMajor> Col col_unchecked:   ,         RepeatWith<I>,
-    GridCol<ColMajor> Col col_unchecked: 'a, &'a     RepeatWith<I>,
-    GridCol<ColMajor> Col col_unchecked: 'a, &'a mut RepeatWith<I>,
+repeat_with_grid!([1D] self
+    GridCol<ColMajor> Col col_unchecked:   ,         RepeatWith<F>,     F,  self.fun,
+    GridCol<ColMajor> Col col_unchecked: 'a, &'a     RepeatWith<F>, &'a F, &self.fun,
+    GridCol<ColMajor> Col col_unchecked: 'a, &'a mut RepeatWith<F>, &'a F, &self.fun,
 
-    GridRow<RowMajor> Row row_unchecked:   ,         RepeatWith<I>,
-    GridRow<RowMajor> Row row_unchecked: 'a, &'a     RepeatWith<I>,
-    GridRow<RowMajor> Row row_unchecked: 'a, &'a mut RepeatWith<I>,
+    GridRow<RowMajor> Row row_unchecked:   ,         RepeatWith<F>,     F,  self.fun,
+    GridRow<RowMajor> Row row_unchecked: 'a, &'a     RepeatWith<F>, &'a F, &self.fun,
+    GridRow<RowMajor> Row row_unchecked: 'a, &'a mut RepeatWith<F>, &'a F, &self.fun,
 );
-repeat_with_grid!([2D]
-    GridCols Index2D Cols cols_unchecked Iter2D<ColMajor, I>:   ,         RepeatWith<I>,
-    GridCols Index2D Cols cols_unchecked Iter2D<ColMajor, I>: 'a, &'a     RepeatWith<I>,
-    GridCols Index2D Cols cols_unchecked Iter2D<ColMajor, I>: 'a, &'a mut RepeatWith<I>,
+repeat_with_grid!([2D] self
+    GridCols Index2D Cols cols_unchecked Iter2D<ColMajor>:   ,         RepeatWith<F>,     F,  self.fun, Clone,
+    GridCols Index2D Cols cols_unchecked Iter2D<ColMajor>: 'a, &'a     RepeatWith<F>, &'a F, &self.fun,      ,
+    GridCols Index2D Cols cols_unchecked Iter2D<ColMajor>: 'a, &'a mut RepeatWith<F>, &'a F, &self.fun,      ,
 
-    GridRows Index2D Rows rows_unchecked Iter2D<RowMajor, I>:   ,         RepeatWith<I>,
-    GridRows Index2D Rows rows_unchecked Iter2D<RowMajor, I>: 'a, &'a     RepeatWith<I>,
-    GridRows Index2D Rows rows_unchecked Iter2D<RowMajor, I>: 'a, &'a mut RepeatWith<I>,
+    GridRows Index2D Rows rows_unchecked Iter2D<RowMajor>:   ,         RepeatWith<F>,     F,  self.fun, Clone,
+    GridRows Index2D Rows rows_unchecked Iter2D<RowMajor>: 'a, &'a     RepeatWith<F>, &'a F, &self.fun,      ,
+    GridRows Index2D Rows rows_unchecked Iter2D<RowMajor>: 'a, &'a mut RepeatWith<F>, &'a F, &self.fun,      ,
 
-    GridItems Index2D Items items_unchecked Items<I>:   ,         RepeatWith<I>,
-    GridItems Index2D Items items_unchecked Items<I>: 'a, &'a     RepeatWith<I>,
-    GridItems Index2D Items items_unchecked Items<I>: 'a, &'a mut RepeatWith<I>,
+    GridItems Index2D Items items_unchecked Items:   ,         RepeatWith<F>,     F,  self.fun,      ,
+    GridItems Index2D Items items_unchecked Items: 'a, &'a     RepeatWith<F>, &'a F, &self.fun,      ,
+    GridItems Index2D Items items_unchecked Items: 'a, &'a mut RepeatWith<F>, &'a F, &self.fun,      ,
 );
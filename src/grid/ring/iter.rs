@@ -0,0 +1,116 @@
+//! Iterators for [`RingGrid`](super::RingGrid).
+
+use super::*;
+use std::{iter::FusedIterator, marker::PhantomData, slice};
+
+macro_rules! rows {
+    ($(
+        $(#[$meta:meta])*
+        $Rows:ident $Row:ident $ptr:ty, $ref:ty, $from_raw_parts:ident $iter:ident
+    )*) => { $(
+        $(#[$meta])*
+        #[derive(Debug)]
+        pub struct $Rows<'a, I> {
+            ptr:     $ptr,
+            width:   usize,
+            head:    usize,
+            len:     usize,
+            rect:    Rect,
+            phantom: PhantomData<$ref>,
+        }
+
+        impl<'a, I> $Rows<'a, I> {
+            /// Creates an iterator over the rows of `rect` of a buffer of `len`
+            /// rows of `width` items starting at `head`.
+            ///
+            /// ### Safety
+            ///
+            /// `ptr` must be valid for `width * len` items for `'a`, and
+            /// `rect` must be in bounds.
+            pub(crate) unsafe fn new(
+                ptr: $ptr,
+                width: usize,
+                head: usize,
+                len: usize,
+                rect: Rect,
+            ) -> Self {
+                Self {
+                    ptr,
+                    width,
+                    head,
+                    len,
+                    rect,
+                    phantom: PhantomData,
+                }
+            }
+        }
+
+        impl<'a, I> $Rows<'a, I> {
+            fn row(&self, y: usize) -> slice::$Row<'a, I> {
+                let start = (self.head + y) % self.len * self.width + self.rect.x.start;
+                let len = self.rect.x.end - self.rect.x.start;
+
+                // SAFETY: rows are in bounds, and each row is yielded once
+                unsafe { slice::$from_raw_parts(self.ptr.add(start), len) }.$iter()
+            }
+        }
+
+        impl<'a, I> Iterator for $Rows<'a, I> {
+            type Item = slice::$Row<'a, I>;
+
+            fn next(&mut self) -> Option<Self::Item> {
+                self.nth(0)
+            }
+
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                self.rect.y.size_hint()
+            }
+
+            fn nth(&mut self, n: usize) -> Option<Self::Item> {
+                let y = self.rect.y.nth(n)?;
+
+                Some(self.row(y))
+            }
+        }
+
+        impl<'a, I> DoubleEndedIterator for $Rows<'a, I> {
+            fn next_back(&mut self) -> Option<Self::Item> {
+                self.nth_back(0)
+            }
+
+            fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+                let y = self.rect.y.nth_back(n)?;
+
+                Some(self.row(y))
+            }
+        }
+
+        impl<'a, I> ExactSizeIterator for $Rows<'a, I> {}
+
+        impl<'a, I> FusedIterator for $Rows<'a, I> {}
+    )* };
+}
+
+rows!(
+    /// A 2D iterator over the rows of a [`RingGrid`](super::RingGrid).
+    Rows Iter *const I, &'a I, from_raw_parts iter
+    /// A mutable 2D iterator over the rows of a [`RingGrid`](super::RingGrid).
+    RowsMut IterMut *mut I, &'a mut I, from_raw_parts_mut iter_mut
+);
+
+impl<'a, I> Clone for Rows<'a, I> {
+    fn clone(&self) -> Self {
+        Self {
+            rect: self.rect.clone(),
+            ..*self
+        }
+    }
+}
+
+// SAFETY: Rows behaves like &[I]
+unsafe impl<'a, I: Sync> Send for Rows<'a, I> {}
+unsafe impl<'a, I: Sync> Sync for Rows<'a, I> {}
+
+// SAFETY: RowsMut behaves like &mut [I]
+unsafe impl<'a, I: Send> Send for RowsMut<'a, I> {}
+unsafe impl<'a, I: Sync> Sync for RowsMut<'a, I> {}
@@ -0,0 +1,368 @@
+//! A grid whose rows live in a circular buffer.
+//!
+//! This module provides the [`RingGrid`] type, which appends and scrolls rows
+//! in O(width) instead of O(area), like a terminal's scrollback or a log pane.
+//!
+//! Only rows are exposed: [`RingGrid`] implements [`GridRow`], [`GridRows`]
+//! and [`GridItems`], but not [`GridCol`] nor [`GridCols`].
+
+pub mod iter;
+
+use super::*;
+use iter::*;
+use std::{
+    hash::{Hash, Hasher},
+    iter::Flatten,
+};
+
+/// A grid of rows stored in a circular buffer of bounded capacity.
+///
+/// Pushing a row to a full [`RingGrid`] evicts its top row. Scrolling moves
+/// the start of the buffer instead of the items.
+///
+/// You can get an [`Item`](Grid::Item) through the [`Grid`] trait, and
+/// iterate over rows through the [`GridRow`], [`GridRows`] and [`GridItems`]
+/// traits, both immutably and mutably.
+///
+/// # Example
+///
+/// ```
+/// # use tender::grid::*;
+/// let mut grid = RingGrid::new(2, 2);
+/// grid.push_row([1, 2]);
+/// grid.push_row([3, 4]);
+/// grid.push_row([5, 6]);
+///
+/// assert!((&grid).flatten_rows().eq(&[3, 4, 5, 6]));
+///
+/// grid.scroll_up(1, 0);
+/// assert!((&grid).flatten_rows().eq(&[5, 6, 0, 0]));
+/// ```
+#[derive(Clone, Debug)]
+pub struct RingGrid<I> {
+    width:    usize,
+    capacity: usize,
+    len:      usize,
+    /// The buffer row of the top row.
+    head:     usize,
+    items:    Vec<I>,
+}
+
+impl<I> RingGrid<I> {
+    /// Creates a new empty [`RingGrid`] of `width`, holding at most
+    /// `capacity` rows.
+    ///
+    /// ### Panics
+    ///
+    /// Panics if `width` or `capacity` is 0.
+    pub fn new(width: usize, capacity: usize) -> Self {
+        assert!(width != 0, "Width is zero");
+        assert!(capacity != 0, "Capacity is zero");
+
+        Self {
+            width,
+            capacity,
+            len: 0,
+            head: 0,
+            items: Vec::with_capacity(width * capacity),
+        }
+    }
+
+    /// Creates a new full [`RingGrid`] of `capacity` rows of `width` items
+    /// set to `fill`.
+    ///
+    /// ### Panics
+    ///
+    /// Panics if `width` or `capacity` is 0.
+    pub fn filled(width: usize, capacity: usize, fill: I) -> Self
+    where
+        I: Clone,
+    {
+        let mut grid = Self::new(width, capacity);
+        grid.items.resize(width * capacity, fill);
+        grid.len = capacity;
+
+        grid
+    }
+
+    /// Returns the maximum number of rows.
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Returns `true` if there are `capacity` rows.
+    pub fn is_full(&self) -> bool {
+        self.len == self.capacity
+    }
+
+    /// Appends `row` at the bottom, evicting the top row if full.
+    ///
+    /// This is O(width), unless the grid is not full and has been scrolled.
+    ///
+    /// ### Panics
+    ///
+    /// Panics if the length of `row` does not match the width.
+    pub fn push_row(&mut self, row: impl IntoIterator<Item = I>) {
+        let row = row.into_iter().collect::<Vec<_>>();
+        assert!(row.len() == self.width, "Pushed length does not match");
+
+        if self.is_full() {
+            // Overwrite the top row, which becomes the bottom row
+            let start = self.head * self.width;
+            self.items.splice(start..start + self.width, row);
+
+            self.head = (self.head + 1) % self.len;
+        } else {
+            // Insert after the bottom row
+            let start = if self.head == 0 {
+                self.items.len()
+            } else {
+                self.head * self.width
+            };
+            self.items.splice(start..start, row);
+
+            if self.head != 0 {
+                self.head += 1;
+            }
+            self.len += 1;
+        }
+    }
+
+    /// Moves rows up by `n`, dropping the top rows and filling the bottom
+    /// rows with `fill`.
+    pub fn scroll_up(&mut self, n: usize, fill: I)
+    where
+        I: Clone,
+    {
+        let n = n.min(self.len);
+
+        // The top rows become the bottom rows
+        for y in 0..n {
+            self.fill_row(y, &fill);
+        }
+        if n != 0 {
+            self.head = self.buffer_row(n);
+        }
+    }
+
+    /// Moves rows down by `n`, dropping the bottom rows and filling the top
+    /// rows with `fill`.
+    pub fn scroll_down(&mut self, n: usize, fill: I)
+    where
+        I: Clone,
+    {
+        let n = n.min(self.len);
+
+        // The bottom rows become the top rows
+        for y in self.len - n..self.len {
+            self.fill_row(y, &fill);
+        }
+        if n != 0 {
+            self.head = self.buffer_row(self.len - n);
+        }
+    }
+
+    /// Removes all rows.
+    pub fn clear(&mut self) {
+        self.items.clear();
+        self.len = 0;
+        self.head = 0;
+    }
+
+    /// Returns the buffer row of the row `y`.
+    fn buffer_row(&self, y: usize) -> usize {
+        (self.head + y) % self.len
+    }
+
+    fn fill_row(&mut self, y: usize, fill: &I)
+    where
+        I: Clone,
+    {
+        let start = self.buffer_row(y) * self.width;
+
+        self.items[start..start + self.width]
+            .iter_mut()
+            .for_each(|item| *item = fill.clone());
+    }
+}
+
+/// Grids are equal if they have the same rows, regardless of their capacity
+/// and of how they were scrolled.
+impl<I: PartialEq> PartialEq for RingGrid<I> {
+    fn eq(&self, other: &Self) -> bool {
+        self.size() == other.size() && self.flatten_rows().eq(other.flatten_rows())
+    }
+}
+
+impl<I: Eq> Eq for RingGrid<I> {}
+
+impl<I: Hash> Hash for RingGrid<I> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.size().hash(state);
+        self.flatten_rows().for_each(|item| item.hash(state));
+    }
+}
+
+impl<I> WithSize for RingGrid<I> {
+    fn size(&self) -> Size {
+        Size {
+            x: self.width,
+            y: self.len,
+        }
+    }
+}
+
+macro_rules! grid {
+    ($([$($mut:ident)?] $Row:ident $Rows:ident $get:ident $iter:ident $as_ptr:ident)*) => { $(
+        impl<'a, I> Grid for &'a $($mut)? RingGrid<I> {
+            type Item = &'a $($mut)? I;
+
+            unsafe fn item_unchecked(self, index: impl Index0D) -> Self::Item {
+                let Point { x, y } = index.unchecked();
+                let index = self.buffer_row(y) * self.width + x;
+
+                self.items.$get(index)
+            }
+        }
+
+        impl<'a, I> GridRow for &'a $($mut)? RingGrid<I> {
+            type Row = std::slice::$Row<'a, I>;
+
+            unsafe fn row_unchecked(self, index: impl Index1D) -> Self::Row {
+                let (y, x) = index.row_unchecked(self.size());
+                let start = self.buffer_row(y) * self.width;
+
+                self.items
+                    .$get(start + x.start..start + x.end)
+                    .$iter()
+            }
+        }
+
+        impl<'a, I> GridRows for &'a $($mut)? RingGrid<I> {
+            type Rows = $Rows<'a, I>;
+
+            unsafe fn rows_unchecked(self, index: impl Index2D) -> Self::Rows {
+                let rect = index.unchecked(self.size());
+
+                $Rows::new(self.items.$as_ptr(), self.width, self.head, self.len, rect)
+            }
+        }
+
+        impl<'a, I> GridItems for &'a $($mut)? RingGrid<I> {
+            type Items = Flatten<$Rows<'a, I>>;
+
+            unsafe fn items_unchecked(self, index: impl Index2D) -> Self::Items {
+                self.rows_unchecked(index).flatten()
+            }
+        }
+    )* };
+}
+
+grid!(
+    []    Iter    Rows    get_unchecked     iter     as_ptr
+    [mut] IterMut RowsMut get_unchecked_mut iter_mut as_mut_ptr
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::canvas::Layer;
+    use pretty_assertions::assert_eq;
+    use std::{
+        collections::hash_map::DefaultHasher,
+        panic::{catch_unwind, AssertUnwindSafe},
+    };
+
+    fn rows(grid: &RingGrid<i32>) -> Vec<Vec<i32>> {
+        grid.rows(..)
+            .unwrap()
+            .map(|row| row.copied().collect())
+            .collect()
+    }
+
+    fn hash(grid: &RingGrid<i32>) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        grid.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    #[test]
+    fn push_while_not_full_after_scroll() {
+        let mut grid = RingGrid::new(2, 4);
+        grid.push_row([1, 2]);
+        grid.push_row([3, 4]);
+        grid.push_row([5, 6]);
+
+        grid.scroll_up(1, 0);
+        assert_eq!(rows(&grid), [[3, 4], [5, 6], [0, 0]]);
+
+        grid.push_row([7, 8]);
+        assert_eq!(rows(&grid), [[3, 4], [5, 6], [0, 0], [7, 8]]);
+        assert!(grid.is_full());
+
+        grid.push_row([9, 9]);
+        assert_eq!(rows(&grid), [[5, 6], [0, 0], [7, 8], [9, 9]]);
+    }
+
+    #[test]
+    fn scroll_wraps() {
+        let mut grid = RingGrid::filled(1, 3, 0);
+        for i in 1..=3 {
+            grid.push_row([i]);
+        }
+
+        grid.scroll_up(2, 0);
+        assert_eq!(rows(&grid), [[3], [0], [0]]);
+
+        grid.scroll_down(1, 4);
+        assert_eq!(rows(&grid), [[4], [3], [0]]);
+
+        grid.scroll_down(2, 5);
+        assert_eq!(rows(&grid), [[5], [5], [4]]);
+
+        grid.scroll_up(5, 6);
+        assert_eq!(rows(&grid), [[6], [6], [6]]);
+    }
+
+    #[test]
+    fn short_push_keeps_grid_valid() {
+        let mut grid = RingGrid::filled(3, 2, 0);
+
+        let panicked = catch_unwind(AssertUnwindSafe(|| grid.push_row([1])));
+        assert!(panicked.is_err());
+        assert_eq!(rows(&grid), [[0, 0, 0], [0, 0, 0]]);
+        assert_eq!((&grid).item((2, 1)), Some(&0));
+    }
+
+    #[test]
+    fn eq_and_hash_ignore_scrolling() {
+        let mut a = RingGrid::new(1, 2);
+        a.push_row([1]);
+        a.push_row([2]);
+        a.push_row([3]);
+
+        let mut b = RingGrid::new(1, 3);
+        b.push_row([2]);
+        b.push_row([3]);
+
+        assert_eq!(a, b);
+        assert_eq!(hash(&a), hash(&b));
+
+        b.push_row([4]);
+        assert_ne!(a, b);
+    }
+
+    #[test]
+    fn layer() {
+        let mut layer = Layer::<_>::new((-1, 2), RingGrid::new(2, 2));
+        layer.grid_mut().push_row([1, 2]);
+        layer.grid_mut().push_row([3, 4]);
+        layer.grid_mut().push_row([5, 6]);
+
+        assert_eq!(layer.size(), Size { x: 2, y: 2 });
+        assert_eq!(layer.item_at((-1, 2)), Some(&3));
+        assert_eq!(layer.item_at((0, 3)), Some(&6));
+        assert_eq!(layer.item_at((1, 3)), None);
+        assert_eq!(layer.find(|&&i| i == 5), Some((Point { x: -1, y: 3 }, &5)));
+    }
+}
@@ -0,0 +1,182 @@
+//! Iterators for [`Scale`](super::Scale)'s and
+//! [`Downscale`](super::Downscale)'s `Grid*` implementations.
+
+use super::*;
+use std::{iter::FusedIterator, marker::PhantomData};
+
+/// A 1D iterator repeating the items of [`Scale`](super::Scale)'s inner
+/// column/row.
+#[derive(Clone, Debug)]
+pub struct Stretch<U: Iterator> {
+    iter:   U,
+    factor: usize,
+    /// Positions left to yield.
+    range:  Range<usize>,
+    /// Index of the next item of `iter` from the front.
+    front:  usize,
+    /// Index of the next item of `iter` from the back, plus one.
+    back:   usize,
+    /// Last item taken from the front, with its index.
+    first:  Option<(usize, U::Item)>,
+    /// Last item taken from the back, with its index.
+    last:   Option<(usize, U::Item)>,
+}
+
+impl<U: Iterator> Stretch<U> {
+    /// Repeats the items of `iter` `factor` times, in `range`.
+    ///
+    /// `iter` must yield the items containing `range`, i.e. from
+    /// `range.start / factor` to `range.end.div_ceil(factor)`.
+    pub(crate) fn new(iter: U, factor: usize, range: Range<usize>) -> Self {
+        Self {
+            iter,
+            factor,
+            front: range.start / factor,
+            back: range.end.div_ceil(factor),
+            range,
+            first: None,
+            last: None,
+        }
+    }
+
+    /// Returns the item with index `i`, if already taken.
+    fn cached(&self, i: usize) -> Option<U::Item>
+    where
+        U::Item: Clone,
+    {
+        self.first
+            .iter()
+            .chain(&self.last)
+            .find(|(j, _)| *j == i)
+            .map(|(_, item)| item.clone())
+    }
+}
+
+impl<U: Iterator> Iterator for Stretch<U>
+where
+    U::Item: Clone,
+{
+    type Item = U::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let i = self.range.next()? / self.factor;
+
+        if let Some(item) = self.cached(i) {
+            return Some(item);
+        }
+
+        let item = self.iter.nth(i - self.front)?;
+        self.front = i + 1;
+        self.first = Some((i, item.clone()));
+
+        Some(item)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.range.size_hint()
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        if n < self.range.len() {
+            self.range.start += n;
+
+            self.next()
+        } else {
+            self.range.start = self.range.end;
+
+            None
+        }
+    }
+}
+
+impl<U: DoubleEndedIterator> DoubleEndedIterator for Stretch<U>
+where
+    U::Item: Clone,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let i = self.range.next_back()? / self.factor;
+
+        if let Some(item) = self.cached(i) {
+            return Some(item);
+        }
+
+        let item = self.iter.nth_back(self.back - i - 1)?;
+        self.back = i;
+        self.last = Some((i, item.clone()));
+
+        Some(item)
+    }
+
+    fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+        if n < self.range.len() {
+            self.range.end -= n;
+
+            self.next_back()
+        } else {
+            self.range.end = self.range.start;
+
+            None
+        }
+    }
+}
+
+impl<U: Iterator> ExactSizeIterator for Stretch<U> where U::Item: Clone {}
+
+impl<U: Iterator> FusedIterator for Stretch<U> where U::Item: Clone {}
+
+/// A 1D iterator over [`Downscale`](super::Downscale)'s reduced blocks.
+#[derive(Clone, Debug)]
+pub struct Blocks<M, T> {
+    grid:     T,
+    index:    (usize, Range<usize>),
+    _phantom: PhantomData<M>,
+}
+
+impl<M, T> Blocks<M, T> {
+    /// Yields the items of `grid` along `index`.
+    ///
+    /// `index` must be in bounds.
+    pub(crate) fn new(grid: T, index: (usize, Range<usize>)) -> Self {
+        Self {
+            grid,
+            index,
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<M: Major, T: Grid + Clone> Iterator for Blocks<M, T> {
+    type Item = T::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.nth(0)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.index.1.size_hint()
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        let (i, current) = (self.index.0, self.index.1.nth(n)?);
+
+        // SAFETY: users guaranty index is in bounds at construction
+        Some(unsafe { self.grid.clone().item_unchecked(M::new(current, i).into()) })
+    }
+}
+
+impl<M: Major, T: Grid + Clone> DoubleEndedIterator for Blocks<M, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.nth_back(0)
+    }
+
+    fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+        let (i, current) = (self.index.0, self.index.1.nth_back(n)?);
+
+        // SAFETY: users guaranty index is in bounds at construction
+        Some(unsafe { self.grid.clone().item_unchecked(M::new(current, i).into()) })
+    }
+}
+
+impl<M: Major, T: Grid + Clone> ExactSizeIterator for Blocks<M, T> {}
+
+impl<M: Major, T: Grid + Clone> FusedIterator for Blocks<M, T> {}
@@ -0,0 +1,359 @@
+//! Grids that scale grids up or down by integer factors.
+
+pub mod iter;
+
+use super::*;
+use iter::*;
+use std::{iter::Flatten, ops::Range};
+use tiles::iter::Lines;
+
+type Iter<T> = <T as IntoIterator>::IntoIter;
+
+// ----------------------------------------------------------- //
+//                                                             //
+// ************************** SCALE ************************** //
+//                                                             //
+// ----------------------------------------------------------- //
+
+/// A grid that repeats each item of a grid `factor.x` times horizontally and
+/// `factor.y` times vertically.
+///
+/// See [`Grid::scale()`].
+#[derive(Copy, Clone, Default, Debug)]
+pub struct Scale<T> {
+    grid:   T,
+    factor: Size,
+    size:   Size,
+}
+
+impl<T: WithSize> Scale<T> {
+    pub(crate) fn new(grid: T, factor: Size) -> Self {
+        assert!(factor.x != 0 && factor.y != 0, "Scale factor is zero");
+
+        let size = grid.size();
+        let size = Size {
+            x: size.x.checked_mul(factor.x).expect("Scale size overflows"),
+            y: size.y.checked_mul(factor.y).expect("Scale size overflows"),
+        };
+
+        Self { grid, factor, size }
+    }
+}
+
+impl<T> Scale<T> {
+    /// Returns the scale factor.
+    pub fn factor(&self) -> Size {
+        self.factor
+    }
+
+    /// Returns the underlying grid.
+    pub fn into_inner(self) -> T {
+        self.grid
+    }
+}
+
+impl<T> WithSize for Scale<T> {
+    fn size(&self) -> Size {
+        self.size
+    }
+}
+
+impl<T: Grid> Grid for Scale<T> {
+    type Item = T::Item;
+
+    unsafe fn item_unchecked(self, index: impl Index0D) -> Self::Item {
+        let Point { x, y } = index.unchecked();
+
+        self.grid
+            .item_unchecked((x / self.factor.x, y / self.factor.y))
+    }
+}
+
+macro_rules! scale1d {
+    ($($Trait:ident $Assoc:ident $fn:ident $i:ident $range:ident)*) => { $(
+        impl<T: $Trait> $Trait for Scale<T>
+        where
+            T::Item: Clone,
+        {
+            type $Assoc = Stretch<Iter<T::$Assoc>>;
+
+            unsafe fn $fn(self, index: impl Index1D) -> Self::$Assoc {
+                let (i, range) = index.$fn(self.size);
+                let factor = self.factor.$range;
+                let inner = (
+                    i / self.factor.$i,
+                    range.start / factor..range.end.div_ceil(factor),
+                );
+
+                Stretch::new(self.grid.$fn(inner).into_iter(), factor, range)
+            }
+        }
+    )* };
+}
+
+macro_rules! scale2d {
+    ($($Trait:ident $Assoc:ident $fn:ident ($Parent:ident $Line:ident $line_fn:ident $i:ident $range:ident))*) => { $(
+        impl<T: $Parent + Clone> $Trait for Scale<T>
+        where
+            T::Item: Clone,
+        {
+            type $Assoc = Lines<Self, fn(Self, (usize, Range<usize>)) -> <Self as $Parent>::$Line>;
+
+            unsafe fn $fn(self, index: impl Index2D) -> Self::$Assoc {
+                let rect = index.unchecked(self.size);
+
+                Lines::new(
+                    self,
+                    // SAFETY: the index is in the bounds of the rect
+                    |grid, index| unsafe { grid.$line_fn(index) },
+                    rect.$i,
+                    rect.$range,
+                )
+            }
+        }
+    )* };
+}
+
+scale1d!(
+    GridCol Col col_unchecked x y
+    GridRow Row row_unchecked y x
+);
+
+scale2d!(
+    GridCols Cols cols_unchecked (GridCol Col col_unchecked x y)
+    GridRows Rows rows_unchecked (GridRow Row row_unchecked y x)
+);
+
+impl<T: GridRow + Clone> GridItems for Scale<T>
+where
+    T::Item: Clone,
+{
+    type Items = Flatten<<Self as GridRows>::Rows>;
+
+    unsafe fn items_unchecked(self, index: impl Index2D) -> Self::Items {
+        self.rows_unchecked(index).flatten()
+    }
+}
+
+// ----------------------------------------------------------- //
+//                                                             //
+// ************************ DOWNSCALE ************************ //
+//                                                             //
+// ----------------------------------------------------------- //
+
+/// A grid that reduces each block of `factor` items of a grid to a single
+/// item.
+///
+/// Blocks on the right and bottom edges are smaller when the grid's size is
+/// not a multiple of `factor`, just like [`Chunks`].
+///
+/// See [`Grid::downscale()`].
+#[derive(Copy, Clone, Debug)]
+pub struct Downscale<T, F> {
+    grid:   T,
+    fun:    F,
+    factor: Size,
+    size:   Size,
+}
+
+impl<T: WithSize, F> Downscale<T, F> {
+    pub(crate) fn new(grid: T, factor: Size, fun: F) -> Self {
+        assert!(factor.x != 0 && factor.y != 0, "Downscale factor is zero");
+
+        let size = grid.size();
+        let size = Size {
+            x: size.x.div_ceil(factor.x),
+            y: size.y.div_ceil(factor.y),
+        };
+
+        Self {
+            grid,
+            fun,
+            factor,
+            size,
+        }
+    }
+}
+
+impl<T, F> Downscale<T, F> {
+    /// Returns the downscale factor.
+    pub fn factor(&self) -> Size {
+        self.factor
+    }
+
+    /// Returns the underlying grid.
+    pub fn into_inner(self) -> T {
+        self.grid
+    }
+}
+
+impl<T, F> WithSize for Downscale<T, F> {
+    fn size(&self) -> Size {
+        self.size
+    }
+}
+
+impl<T: Grid, F: Fn(Crop<T>) -> I, I> Grid for Downscale<T, F> {
+    type Item = I;
+
+    unsafe fn item_unchecked(self, index: impl Index0D) -> Self::Item {
+        let Point { x, y } = index.unchecked();
+        let start = Point {
+            x: x * self.factor.x,
+            y: y * self.factor.y,
+        };
+        let rect = start.rect(self.factor).crop(self.grid.size());
+
+        (self.fun)(self.grid.crop_unchecked(rect))
+    }
+}
+
+macro_rules! downscale1d {
+    ($($Trait:ident<$M:ident> $Assoc:ident $fn:ident)*) => { $(
+        impl<T: Grid + Clone, F: Fn(Crop<T>) -> I + Clone, I> $Trait for Downscale<T, F> {
+            type $Assoc = Blocks<$M, Self>;
+
+            unsafe fn $fn(self, index: impl Index1D) -> Self::$Assoc {
+                let index = index.$fn(self.size);
+
+                Blocks::new(self, index)
+            }
+        }
+    )* };
+}
+
+macro_rules! downscale2d {
+    ($($Trait:ident $Assoc:ident $fn:ident ($Parent:ident $Line:ident $line_fn:ident $i:ident $range:ident))*) => { $(
+        impl<T: Grid + Clone, F: Fn(Crop<T>) -> I + Clone, I> $Trait for Downscale<T, F> {
+            type $Assoc = Lines<Self, fn(Self, (usize, Range<usize>)) -> <Self as $Parent>::$Line>;
+
+            unsafe fn $fn(self, index: impl Index2D) -> Self::$Assoc {
+                let rect = index.unchecked(self.size);
+
+                Lines::new(
+                    self,
+                    // SAFETY: the index is in the bounds of the rect
+                    |grid, index| unsafe { grid.$line_fn(index) },
+                    rect.$i,
+                    rect.$range,
+                )
+            }
+        }
+    )* };
+}
+
+downscale1d!(
+    GridCol<ColMajor> Col col_unchecked
+    GridRow<RowMajor> Row row_unchecked
+);
+
+downscale2d!(
+    GridCols Cols cols_unchecked (GridCol Col col_unchecked x y)
+    GridRows Rows rows_unchecked (GridRow Row row_unchecked y x)
+);
+
+impl<T: Grid + Clone, F: Fn(Crop<T>) -> I + Clone, I> GridItems for Downscale<T, F> {
+    type Items = Flatten<<Self as GridRows>::Rows>;
+
+    unsafe fn items_unchecked(self, index: impl Index2D) -> Self::Items {
+        self.rows_unchecked(index).flatten()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    fn grid() -> RowVec1D<u8> {
+        RowVec1D::new((3, 2), vec![1, 2, 3, 4, 5, 6]).unwrap()
+    }
+
+    #[test]
+    fn partial_rows() {
+        let grid = grid();
+        let scale = (&grid).scale((3, 2));
+        let row = |index| scale.row(index).unwrap().copied().collect::<Vec<_>>();
+
+        assert_eq!(row((0, 2..7)), [1, 2, 2, 2, 3]);
+        assert_eq!(row((3, 4..5)), [5]);
+        assert_eq!(row((1, 8..9)), [3]);
+        assert_eq!(row((2, 5..5)), []);
+        assert_eq!(row((2, 0..9)), [4, 4, 4, 5, 5, 5, 6, 6, 6]);
+        assert!(scale.row((0, 2..10)).is_none());
+    }
+
+    #[test]
+    fn partial_cols() {
+        let grid = grid();
+        let scale = (&grid).scale((1, 3));
+        let col = |index| scale.col(index).unwrap().copied().collect::<Vec<_>>();
+
+        assert_eq!(col((1, 1..5)), [2, 2, 5, 5]);
+        assert_eq!(col((2, 5..6)), [6]);
+    }
+
+    #[test]
+    fn double_ended_rows() {
+        let grid = grid();
+        let scale = (&grid).scale((3, 1));
+
+        let row = scale.row((0, 1..8)).unwrap().rev().copied().collect::<Vec<_>>();
+        assert_eq!(row, [3, 3, 2, 2, 2, 1, 1]);
+
+        // Both ends meet in the same item
+        let mut row = scale.row((0, 1..8)).unwrap().copied();
+        assert_eq!(row.len(), 7);
+        assert_eq!(row.next(), Some(1));
+        assert_eq!(row.next_back(), Some(3));
+        assert_eq!(row.nth(1), Some(2));
+        assert_eq!(row.nth_back(1), Some(2));
+        assert_eq!(row.len(), 1);
+        assert_eq!(row.next_back(), Some(2));
+        assert_eq!(row.next(), None);
+        assert_eq!(row.next_back(), None);
+
+        let mut row = scale.row((1, ..)).unwrap().copied();
+        assert_eq!(row.nth(7), Some(6));
+        assert_eq!(row.nth_back(1), None);
+        assert_eq!(row.next(), None);
+
+        let rows = scale
+            .rows(..)
+            .unwrap()
+            .rev()
+            .map(|row| row.rev().copied().collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+        assert_eq!(rows, [[6, 6, 6, 5, 5, 5, 4, 4, 4], [3, 3, 3, 2, 2, 2, 1, 1, 1]]);
+    }
+
+    #[test]
+    fn downscale_ragged_edges() {
+        let grid = RowVec1D::new((5, 3), (0..15).collect::<Vec<u32>>()).unwrap();
+        let downscale = (&grid).downscale((2, 2), |block| {
+            let Size { x, y } = block.size();
+            (x * y, block.items(..).unwrap().sum::<u32>())
+        });
+
+        assert_eq!(downscale.size(), Size { x: 3, y: 2 });
+
+        let rows = downscale
+            .rows(..)
+            .unwrap()
+            .map(|row| row.collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+        assert_eq!(rows, [
+            [(4, 12), (4, 20), (2, 13)],
+            [(2, 21), (2, 25), (1, 14)],
+        ]);
+
+        let col = downscale.col((2, ..)).unwrap().rev().collect::<Vec<_>>();
+        assert_eq!(col, [(1, 14), (2, 13)]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Scale size overflows")]
+    fn scale_overflows() {
+        repeat((usize::MAX / 2 + 1, 1), 0).scale((2, 1));
+    }
+}
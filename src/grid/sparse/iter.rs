@@ -0,0 +1,174 @@
+//! Iterators for [`SparseGrid`](super::SparseGrid).
+
+use super::*;
+use std::{
+    collections::btree_map::{self, BTreeMap},
+    iter::Peekable,
+    ops::Range,
+};
+
+/// An iterator over the occupied cells of a [`SparseGrid`], with their
+/// [`Point`].
+#[derive(Clone, Debug)]
+pub struct Occupied<'a, I> {
+    items: btree_map::Iter<'a, (usize, usize), I>,
+}
+
+impl<'a, I> Occupied<'a, I> {
+    pub(crate) fn new(items: btree_map::Iter<'a, (usize, usize), I>) -> Self {
+        Self { items }
+    }
+}
+
+impl<'a, I> Iterator for Occupied<'a, I> {
+    type Item = (Point, &'a I);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (&(y, x), item) = self.items.next()?;
+
+        Some((Point { x, y }, item))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.items.size_hint()
+    }
+}
+
+/// A mutable iterator over the occupied cells of a [`SparseGrid`], with
+/// their [`Point`].
+#[derive(Debug)]
+pub struct OccupiedMut<'a, I> {
+    items: btree_map::IterMut<'a, (usize, usize), I>,
+}
+
+impl<'a, I> OccupiedMut<'a, I> {
+    pub(crate) fn new(items: btree_map::IterMut<'a, (usize, usize), I>) -> Self {
+        Self { items }
+    }
+}
+
+impl<'a, I> Iterator for OccupiedMut<'a, I> {
+    type Item = (Point, &'a mut I);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (&(y, x), item) = self.items.next()?;
+
+        Some((Point { x, y }, item))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.items.size_hint()
+    }
+}
+
+/// A 1D iterator over a column of a [`SparseGrid`].
+///
+/// Each cell of the column is looked up.
+#[derive(Clone, Debug)]
+pub struct Col<'a, I> {
+    items: &'a BTreeMap<(usize, usize), I>,
+    x:     usize,
+    y:     Range<usize>,
+}
+
+impl<'a, I> Col<'a, I> {
+    pub(crate) fn new(items: &'a BTreeMap<(usize, usize), I>, x: usize, y: Range<usize>) -> Self {
+        Self { items, x, y }
+    }
+}
+
+impl<'a, I> Iterator for Col<'a, I> {
+    type Item = Option<&'a I>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let y = self.y.next()?;
+
+        Some(self.items.get(&(y, self.x)))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.y.size_hint()
+    }
+}
+
+/// A 1D iterator over a row of a [`SparseGrid`].
+///
+/// Only walks through the occupied cells of the row.
+#[derive(Clone, Debug)]
+pub struct Row<'a, I> {
+    items: Peekable<btree_map::Range<'a, (usize, usize), I>>,
+    x:     Range<usize>,
+}
+
+impl<'a, I> Row<'a, I> {
+    pub(crate) fn new(items: &'a BTreeMap<(usize, usize), I>, y: usize, x: Range<usize>) -> Self {
+        Self {
+            items: items.range((y, x.start)..(y, x.end)).peekable(),
+            x,
+        }
+    }
+}
+
+impl<'a, I> Iterator for Row<'a, I> {
+    type Item = Option<&'a I>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let x = self.x.next()?;
+
+        Some(
+            self.items
+                .next_if(|&(&(_, item_x), _)| item_x == x)
+                .map(|(_, item)| item),
+        )
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.x.size_hint()
+    }
+}
+
+/// A 2D iterator over the columns of a [`SparseGrid`].
+#[derive(Clone, Debug)]
+pub struct Cols<'a, I> {
+    items: &'a BTreeMap<(usize, usize), I>,
+    index: Rect,
+}
+
+impl<'a, I> Cols<'a, I> {
+    pub(crate) fn new(items: &'a BTreeMap<(usize, usize), I>, index: Rect) -> Self {
+        Self { items, index }
+    }
+}
+
+impl<'a, I> Iterator for Cols<'a, I> {
+    type Item = Col<'a, I>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let x = self.index.x.next()?;
+
+        Some(Col::new(self.items, x, self.index.y.clone()))
+    }
+}
+
+/// A 2D iterator over the rows of a [`SparseGrid`].
+#[derive(Clone, Debug)]
+pub struct Rows<'a, I> {
+    items: &'a BTreeMap<(usize, usize), I>,
+    index: Rect,
+}
+
+impl<'a, I> Rows<'a, I> {
+    pub(crate) fn new(items: &'a BTreeMap<(usize, usize), I>, index: Rect) -> Self {
+        Self { items, index }
+    }
+}
+
+impl<'a, I> Iterator for Rows<'a, I> {
+    type Item = Row<'a, I>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let y = self.index.y.next()?;
+
+        Some(Row::new(self.items, y, self.index.x.clone()))
+    }
+}
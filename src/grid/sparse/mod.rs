@@ -0,0 +1,247 @@
+//! A sparse grid backed by a map.
+//!
+//! This module provides the [`SparseGrid`] type, which only stores occupied
+//! cells. Querying an empty cell yields [`None`].
+//!
+//! Occupied cells are stored row by row: [`GridRow`] is efficient whereas
+//! [`GridCol`] looks up each cell of the column.
+
+pub mod iter;
+
+use super::*;
+use iter::*;
+use std::{collections::BTreeMap, iter::Flatten};
+
+/// A grid storing only its occupied cells.
+///
+/// You can get an `Option<&I>` [`Item`](Grid::Item) through the [`Grid`]
+/// trait, and iterate over all cells through the [`GridCol`], [`GridRow`],
+/// [`GridCols`], [`GridRows`] and [`GridItems`] traits, immutably.
+///
+/// Use [`iter()`](SparseGrid::iter)/[`iter_mut()`](SparseGrid::iter_mut) to
+/// iterate over occupied cells only.
+///
+/// # Example
+///
+/// ```
+/// # use tender::grid::*;
+/// let mut grid = SparseGrid::new((3, 2));
+/// grid.insert((1, 0), 'a');
+/// grid.insert((2, 1), 'b');
+/// let mut rows = (&grid).rows(..).unwrap();
+///
+/// assert!(rows.next().unwrap().eq([None, Some(&'a'), None]));
+/// assert!(rows.next().unwrap().eq([None, None, Some(&'b')]));
+/// assert!(rows.next().is_none());
+/// assert_eq!(grid.iter().count(), 2);
+/// ```
+#[derive(Clone, Eq, PartialEq, Hash, Default, Debug)]
+pub struct SparseGrid<I> {
+    size:  Size,
+    /// Items keyed by `(y, x)`, so that they are sorted row by row.
+    items: BTreeMap<(usize, usize), I>,
+}
+
+impl<I> SparseGrid<I> {
+    /// Creates a new empty [`SparseGrid`] of `size`.
+    pub fn new(size: impl Into<Size>) -> Self {
+        Self {
+            size:  size.into(),
+            items: BTreeMap::new(),
+        }
+    }
+
+    /// Inserts `item` at `index`, returning the previous item if any.
+    ///
+    /// ### Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    pub fn insert(&mut self, index: impl Index0D, item: I) -> Option<I> {
+        let Point { x, y } = index.checked(self.size).expect("Index out of bounds");
+
+        self.items.insert((y, x), item)
+    }
+
+    /// Removes the item at `index`, returning it if any.
+    pub fn remove(&mut self, index: impl Index0D) -> Option<I> {
+        let Point { x, y } = index.unchecked();
+
+        self.items.remove(&(y, x))
+    }
+
+    /// Returns a mutable reference to the item at `index`, if any.
+    pub fn get_mut(&mut self, index: impl Index0D) -> Option<&mut I> {
+        let Point { x, y } = index.unchecked();
+
+        self.items.get_mut(&(y, x))
+    }
+
+    /// Removes all items.
+    pub fn clear(&mut self) {
+        self.items.clear();
+    }
+
+    /// Returns the number of occupied cells.
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    /// Returns `true` if no cell is occupied.
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    /// Returns an iterator over occupied cells with their [`Point`].
+    ///
+    /// Cells are yielded row by row.
+    pub fn iter(&self) -> Occupied<'_, I> {
+        Occupied::new(self.items.iter())
+    }
+
+    /// Returns a mutable iterator over occupied cells with their [`Point`].
+    ///
+    /// Cells are yielded row by row.
+    pub fn iter_mut(&mut self) -> OccupiedMut<'_, I> {
+        OccupiedMut::new(self.items.iter_mut())
+    }
+}
+
+impl<I> WithSize for SparseGrid<I> {
+    fn size(&self) -> Size {
+        self.size
+    }
+}
+
+impl<'a, I> Grid for &'a SparseGrid<I> {
+    type Item = Option<&'a I>;
+
+    unsafe fn item_unchecked(self, index: impl Index0D) -> Self::Item {
+        let Point { x, y } = index.unchecked();
+
+        self.items.get(&(y, x))
+    }
+}
+
+impl<'a, I> GridCol for &'a SparseGrid<I> {
+    type Col = Col<'a, I>;
+
+    unsafe fn col_unchecked(self, index: impl Index1D) -> Self::Col {
+        let (x, y) = index.col_unchecked(self.size);
+
+        Col::new(&self.items, x, y)
+    }
+}
+
+impl<'a, I> GridRow for &'a SparseGrid<I> {
+    type Row = Row<'a, I>;
+
+    unsafe fn row_unchecked(self, index: impl Index1D) -> Self::Row {
+        let (y, x) = index.row_unchecked(self.size);
+
+        Row::new(&self.items, y, x)
+    }
+}
+
+impl<'a, I> GridCols for &'a SparseGrid<I> {
+    type Cols = Cols<'a, I>;
+
+    unsafe fn cols_unchecked(self, index: impl Index2D) -> Self::Cols {
+        Cols::new(&self.items, index.unchecked(self.size))
+    }
+}
+
+impl<'a, I> GridRows for &'a SparseGrid<I> {
+    type Rows = Rows<'a, I>;
+
+    unsafe fn rows_unchecked(self, index: impl Index2D) -> Self::Rows {
+        Rows::new(&self.items, index.unchecked(self.size))
+    }
+}
+
+impl<'a, I> GridItems for &'a SparseGrid<I> {
+    type Items = Flatten<Rows<'a, I>>;
+
+    unsafe fn items_unchecked(self, index: impl Index2D) -> Self::Items {
+        self.rows_unchecked(index).flatten()
+    }
+}
+
+impl<'a, I> IntoIterator for &'a SparseGrid<I> {
+    type IntoIter = Occupied<'a, I>;
+    type Item = (Point, &'a I);
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, I> IntoIterator for &'a mut SparseGrid<I> {
+    type IntoIter = OccupiedMut<'a, I>;
+    type Item = (Point, &'a mut I);
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    fn grid() -> SparseGrid<char> {
+        let mut grid = SparseGrid::new((4, 3));
+        grid.insert((3, 2), 'c');
+        grid.insert((1, 0), 'a');
+        grid.insert((0, 2), 'b');
+        grid
+    }
+
+    #[test]
+    fn occupied_only() {
+        let mut grid = grid();
+        let p = |x, y| Point { x, y };
+
+        assert_eq!(grid.len(), 3);
+        assert_eq!(grid.iter().size_hint(), (3, Some(3)));
+        assert_eq!(grid.iter().collect::<Vec<_>>(), [
+            (p(1, 0), &'a'),
+            (p(0, 2), &'b'),
+            (p(3, 2), &'c'),
+        ]);
+
+        for (_, item) in &mut grid {
+            *item = item.to_ascii_uppercase();
+        }
+        assert_eq!(grid.remove((0, 2)), Some('B'));
+        assert_eq!(grid.remove((0, 2)), None);
+        assert_eq!(grid.iter().map(|(_, &item)| item).collect::<String>(), "AC");
+
+        grid.clear();
+        assert!(grid.is_empty());
+        assert_eq!(grid.iter().next(), None);
+    }
+
+    #[test]
+    fn lines() {
+        let grid = grid();
+
+        let row = (&grid).row((2, 0..4)).unwrap().collect::<Vec<_>>();
+        assert_eq!(row, [Some(&'b'), None, None, Some(&'c')]);
+        let row = (&grid).row((2, 1..3)).unwrap().collect::<Vec<_>>();
+        assert_eq!(row, [None, None]);
+        let col = (&grid).col(1).unwrap().collect::<Vec<_>>();
+        assert_eq!(col, [Some(&'a'), None, None]);
+
+        let items = (&grid).items(..).unwrap().flatten().collect::<String>();
+        assert_eq!(items, "abc");
+        assert_eq!((&grid).item((3, 2)), Some(Some(&'c')));
+        assert_eq!((&grid).item((4, 2)), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "Index out of bounds")]
+    fn insert_out_of_bounds() {
+        grid().insert((4, 0), 'd');
+    }
+}
@@ -0,0 +1,110 @@
+//! Iterators for [`Tiles`](super::Tiles)' `Grid*` implementations.
+
+use super::*;
+use std::iter::FusedIterator;
+
+/// A 1D iterator chaining the columns/rows of
+/// [`Tiles`](super::Tiles)' tiles.
+#[derive(Clone, Debug)]
+pub struct Line<T, F, U> {
+    tiles: T,
+    fun:   F,
+    inner: Option<U>,
+    i:     usize,
+    len:   usize,
+    tile:  usize,
+    range: Range<usize>,
+}
+
+impl<T, F, U> Line<T, F, U> {
+    /// Chains the `i`th lines of `tiles`, each of length `len`, in `range`.
+    ///
+    /// `tiles` must start with the tile containing `range.start`.
+    pub(crate) fn new(tiles: T, fun: F, i: usize, len: usize, range: Range<usize>) -> Self {
+        Self {
+            tiles,
+            fun,
+            inner: None,
+            i,
+            len,
+            tile: range.start.checked_div(len).unwrap_or(0),
+            range,
+        }
+    }
+}
+
+impl<T: Iterator, F: FnMut(T::Item, (usize, Range<usize>)) -> U, U: Iterator> Iterator
+    for Line<T, F, U>
+{
+    type Item = U::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(item) = self.inner.as_mut().and_then(Iterator::next) {
+                return Some(item);
+            }
+
+            let tile = self.tiles.next()?;
+            let start = self.tile * self.len;
+            let end = start + self.len;
+            let range = self.range.start.max(start) - start..self.range.end.min(end) - start;
+
+            self.tile += 1;
+            self.inner = Some((self.fun)(tile, (self.i, range)));
+        }
+    }
+}
+
+/// A 2D iterator over [`Tiles`](super::Tiles)' columns/rows.
+#[derive(Clone, Debug)]
+pub struct Lines<T, F> {
+    grid:  T,
+    fun:   F,
+    minor: Range<usize>,
+    major: Range<usize>,
+}
+
+impl<T, F> Lines<T, F> {
+    pub(crate) fn new(grid: T, fun: F, minor: Range<usize>, major: Range<usize>) -> Self {
+        Self {
+            grid,
+            fun,
+            minor,
+            major,
+        }
+    }
+}
+
+impl<T: Clone, F: FnMut(T, (usize, Range<usize>)) -> U, U> Iterator for Lines<T, F> {
+    type Item = U;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.nth(0)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.minor.size_hint()
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        let i = self.minor.nth(n)?;
+
+        Some((self.fun)(self.grid.clone(), (i, self.major.clone())))
+    }
+}
+
+impl<T: Clone, F: FnMut(T, (usize, Range<usize>)) -> U, U> DoubleEndedIterator for Lines<T, F> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.nth_back(0)
+    }
+
+    fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+        let i = self.minor.nth_back(n)?;
+
+        Some((self.fun)(self.grid.clone(), (i, self.major.clone())))
+    }
+}
+
+impl<T: Clone, F: FnMut(T, (usize, Range<usize>)) -> U, U> ExactSizeIterator for Lines<T, F> {}
+
+impl<T: Clone, F: FnMut(T, (usize, Range<usize>)) -> U, U> FusedIterator for Lines<T, F> {}
@@ -0,0 +1,330 @@
+//! Grids split into tiles, and tiles joined into grids.
+
+pub mod iter;
+
+use super::*;
+use iter::*;
+use std::{iter::Flatten, ops::Range};
+
+type Iter<T> = <T as IntoIterator>::IntoIter;
+
+// ------------------------------------------------------------ //
+//                                                              //
+// *************************** CHUNKS ************************* //
+//                                                              //
+// ------------------------------------------------------------ //
+
+/// An iterator over non-overlapping [`Crop`]s of a grid.
+///
+/// Chunks are yielded row by row. Chunks on the right and bottom edges are
+/// smaller when the grid's size is not a multiple of the chunks' size.
+///
+/// See [`Grid::chunks()`].
+#[derive(Clone, Debug)]
+pub struct Chunks<T> {
+    grid:  T,
+    size:  Size,
+    end:   Point,
+    point: Point,
+}
+
+impl<T: WithSize> Chunks<T> {
+    pub(crate) fn new(grid: T, size: Size) -> Self {
+        assert!(size.x != 0 && size.y != 0, "Chunk size is zero");
+
+        let end = grid.size();
+
+        Self {
+            grid,
+            size,
+            end,
+            point: Point::ZERO,
+        }
+    }
+}
+
+impl<T: Grid + Clone> Iterator for Chunks<T> {
+    type Item = Crop<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.point.x >= self.end.x || self.point.y >= self.end.y {
+            return None;
+        }
+
+        let rect = self.point.rect(self.size).crop(self.end);
+
+        self.point.x += self.size.x;
+        if self.point.x >= self.end.x {
+            self.point.x = 0;
+            self.point.y += self.size.y;
+        }
+
+        // SAFETY: rect is cropped to the grid's size
+        debug_assert!(rect.clone().checked(self.grid.size()).is_some());
+        Some(unsafe { self.grid.clone().crop_unchecked(rect) })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = if self.point.x >= self.end.x || self.point.y >= self.end.y {
+            0
+        } else {
+            let cols = self.end.x.div_ceil(self.size.x);
+            let rows = (self.end.y - self.point.y).div_ceil(self.size.y);
+
+            rows * cols - self.point.x / self.size.x
+        };
+
+        (len, Some(len))
+    }
+}
+
+impl<T: Grid + Clone> ExactSizeIterator for Chunks<T> {}
+
+// ------------------------------------------------------------ //
+//                                                              //
+// *************************** TILES ************************** //
+//                                                              //
+// ------------------------------------------------------------ //
+
+/// A grid made of a grid of equally sized tiles.
+///
+/// See [`Grid::flatten_tiles()`].
+#[derive(Copy, Clone, Default, Debug)]
+pub struct Tiles<T> {
+    grid: T,
+    tile: Size,
+    size: Size,
+}
+
+impl<T: GridItems + Clone> Tiles<T>
+where
+    T::Item: Grid,
+{
+    pub(crate) fn new(grid: T) -> Option<Self> {
+        // SAFETY: .. is safe
+        let mut sizes = unsafe { grid.clone().items_unchecked(..) }
+            .into_iter()
+            .map(|tile| tile.size());
+        let tile = sizes.next().unwrap_or(Size::ZERO);
+
+        if !sizes.all(|size| size == tile) {
+            return None;
+        }
+
+        let tiles = grid.size();
+        let size = Size {
+            x: tiles.x * tile.x,
+            y: tiles.y * tile.y,
+        };
+
+        Some(Self { grid, tile, size })
+    }
+}
+
+impl<T> Tiles<T> {
+    /// Returns the [`Size`] of the tiles.
+    pub fn tile_size(&self) -> Size {
+        self.tile
+    }
+
+    /// Returns the underlying grid of tiles.
+    pub fn into_inner(self) -> T {
+        self.grid
+    }
+}
+
+impl<T> WithSize for Tiles<T> {
+    fn size(&self) -> Size {
+        self.size
+    }
+}
+
+impl<T: Grid> Grid for Tiles<T>
+where
+    T::Item: Grid,
+{
+    type Item = <T::Item as Grid>::Item;
+
+    unsafe fn item_unchecked(self, index: impl Index0D) -> Self::Item {
+        let Point { x, y } = index.unchecked();
+        let (w, h) = (self.tile.x, self.tile.y);
+
+        self.grid
+            .item_unchecked((x / w, y / h))
+            .item_unchecked((x % w, y % h))
+    }
+}
+
+macro_rules! grid1d {
+    ($($Trait:ident $Assoc:ident $fn:ident $i:ident $range:ident)*) => { $(
+        impl<T: $Trait> $Trait for Tiles<T>
+        where
+            T::Item: $Trait,
+        {
+            type $Assoc = Line<
+                Iter<T::$Assoc>,
+                fn(T::Item, (usize, Range<usize>)) -> Iter<<T::Item as $Trait>::$Assoc>,
+                Iter<<T::Item as $Trait>::$Assoc>,
+            >;
+
+            unsafe fn $fn(self, index: impl Index1D) -> Self::$Assoc {
+                let (i, range) = index.$fn(self.size);
+                let (tile, len) = (self.tile.$i, self.tile.$range);
+                let tiles = self.grid.$fn((i / tile, tiles(range.clone(), len)));
+
+                Line::new(
+                    tiles.into_iter(),
+                    // SAFETY: the index is in the bounds of the tile
+                    |tile, index| unsafe { tile.$fn(index) }.into_iter(),
+                    i % tile,
+                    len,
+                    range,
+                )
+            }
+        }
+    )* };
+}
+
+macro_rules! grid2d {
+    ($($Trait:ident $Assoc:ident $fn:ident ($Parent:ident $Line:ident $line_fn:ident $i:ident $range:ident))*) => { $(
+        impl<T: $Parent + Clone> $Trait for Tiles<T>
+        where
+            T::Item: $Parent,
+        {
+            type $Assoc = Lines<Self, fn(Self, (usize, Range<usize>)) -> <Self as $Parent>::$Line>;
+
+            unsafe fn $fn(self, index: impl Index2D) -> Self::$Assoc {
+                let rect = index.unchecked(self.size);
+
+                Lines::new(
+                    self,
+                    // SAFETY: the index is in the bounds of the rect
+                    |grid, index| unsafe { grid.$line_fn(index) },
+                    rect.$i,
+                    rect.$range,
+                )
+            }
+        }
+    )* };
+}
+
+grid1d!(
+    GridCol Col col_unchecked x y
+    GridRow Row row_unchecked y x
+);
+
+grid2d!(
+    GridCols Cols cols_unchecked (GridCol Col col_unchecked x y)
+    GridRows Rows rows_unchecked (GridRow Row row_unchecked y x)
+);
+
+impl<T: GridRow + Clone> GridItems for Tiles<T>
+where
+    T::Item: GridRow,
+{
+    type Items = Flatten<<Self as GridRows>::Rows>;
+
+    unsafe fn items_unchecked(self, index: impl Index2D) -> Self::Items {
+        self.rows_unchecked(index).flatten()
+    }
+}
+
+/// Returns the range of the tiles of length `len` covering `range`.
+fn tiles(range: Range<usize>, len: usize) -> Range<usize> {
+    if len == 0 || range.start == range.end {
+        0..0
+    } else {
+        range.start / len..range.end.div_ceil(len)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    fn grid(size: (usize, usize), items: impl IntoIterator<Item = i32>) -> RowVec1D<i32> {
+        RowVec1D::new(size, items.into_iter().collect()).unwrap()
+    }
+
+    fn line<'a>(line: impl IntoIterator<Item = &'a i32>) -> Vec<i32> {
+        line.into_iter().copied().collect()
+    }
+
+    fn lines<'a, L: IntoIterator<Item = impl IntoIterator<Item = &'a i32>>>(
+        lines: L,
+    ) -> Vec<Vec<i32>> {
+        lines.into_iter().map(line).collect()
+    }
+
+    #[test]
+    fn ragged_chunks() {
+        let grid = grid((5, 3), 0..15);
+        let mut chunks = (&grid).chunks((2, 2));
+
+        assert_eq!(chunks.len(), 6);
+        let sizes = chunks.clone().map(|chunk| chunk.size()).collect::<Vec<_>>();
+        assert_eq!(sizes, [
+            Size { x: 2, y: 2 },
+            Size { x: 2, y: 2 },
+            Size { x: 1, y: 2 },
+            Size { x: 2, y: 1 },
+            Size { x: 2, y: 1 },
+            Size { x: 1, y: 1 },
+        ]);
+
+        assert_eq!(lines(chunks.nth(2).unwrap().rows(..).unwrap()), [[4], [9]]);
+        assert_eq!(chunks.len(), 3);
+        assert_eq!(lines(chunks.nth(2).unwrap().rows(..).unwrap()), [[14]]);
+        assert_eq!(chunks.len(), 0);
+        assert!(chunks.next().is_none());
+
+        let mut chunks = (&grid).chunks((10, 10));
+        assert_eq!(chunks.next().unwrap().size(), grid.size());
+        assert!(chunks.next().is_none());
+
+        let empty = RowVec1D::<i32>::new((0, 3), vec![]).unwrap();
+        assert_eq!(empty.chunks((1, 1)).len(), 0);
+    }
+
+    #[test]
+    fn tile_seams() {
+        let tiles = RowVec1D::new((2, 2), vec![
+            grid((2, 2), [0, 1, 4, 5]),
+            grid((2, 2), [2, 3, 6, 7]),
+            grid((2, 2), [8, 9, 12, 13]),
+            grid((2, 2), [10, 11, 14, 15]),
+        ])
+        .unwrap();
+        let flat = (&tiles).flatten_tiles().unwrap();
+
+        assert_eq!(flat.size(), Size { x: 4, y: 4 });
+        assert_eq!(flat.tile_size(), Size { x: 2, y: 2 });
+        assert_eq!(flat.item((2, 1)), Some(&6));
+
+        #[rustfmt::skip]
+        assert_eq!(lines(flat.rows(..).unwrap()), [
+            [0, 1, 2, 3],
+            [4, 5, 6, 7],
+            [8, 9, 10, 11],
+            [12, 13, 14, 15],
+        ]);
+        assert_eq!(lines(flat.rows((1..3, 1..3)).unwrap()), [[5, 6], [9, 10]]);
+        let cols = lines(flat.cols((1..3, ..)).unwrap());
+        assert_eq!(cols, [[1, 5, 9, 13], [2, 6, 10, 14]]);
+        assert_eq!(line(flat.row((2, 1..4)).unwrap()), [9, 10, 11]);
+        assert_eq!(line(flat.row((0, 2..4)).unwrap()), [2, 3]);
+        assert_eq!(line(flat.row((0, 2..2)).unwrap()), []);
+        assert_eq!(line(flat.col((3, 1..3)).unwrap()), [7, 11]);
+
+        let rev = flat.rows((3..4, ..)).unwrap().rev().map(line);
+        assert_eq!(rev.collect::<Vec<_>>(), [[15], [11], [7], [3]]);
+    }
+
+    #[test]
+    fn mismatched_tiles() {
+        let tiles = RowVec1D::new((2, 1), vec![grid((2, 2), 0..4), grid((1, 2), 0..2)]).unwrap();
+
+        assert!((&tiles).flatten_tiles().is_none());
+    }
+}
@@ -1,3 +1,4 @@
+use crate::grid::{Axis, GridError};
 use std::ops::{
     Bound::{self, *},
     Range,
@@ -19,6 +20,21 @@ pub trait ToRange: RangeBounds<usize> {
 
     /// Converts to `Range` by cropping.
     fn cropped(self, len: usize) -> Range<usize>;
+
+    /// Converts to `Range` with bounds checking, or a [`GridError`] on `axis`.
+    fn try_checked(self, len: usize, axis: Axis) -> Result<Range<usize>, GridError>
+    where
+        Self: Sized,
+    {
+        let (start, end) = (self.start_bound().cloned(), self.end_bound().cloned());
+
+        self.checked(len).ok_or(GridError::RangeOutOfBounds {
+            axis,
+            start,
+            end,
+            len,
+        })
+    }
 }
 
 macro_rules! to_range {
@@ -0,0 +1,142 @@
+//! A grid that swaps the x and y axis of a grid.
+
+use crate::grid::*;
+
+/// A grid that swaps the x and y axis.
+///
+/// Columns become rows and rows become columns: a column-major grid can be
+/// used where a row-major grid is expected (and vice versa) without copying.
+///
+/// See [`Grid::transpose()`].
+#[derive(Copy, Clone, Default, Debug)]
+pub struct Transpose<T> {
+    grid: T,
+}
+
+impl<T> Transpose<T> {
+    pub(crate) fn new(grid: T) -> Self {
+        Self { grid }
+    }
+
+    /// Returns the underlying grid.
+    pub fn into_inner(self) -> T {
+        self.grid
+    }
+}
+
+impl<T: WithSize> WithSize for Transpose<T> {
+    fn size(&self) -> Size {
+        self.grid.size().transpose()
+    }
+}
+
+impl<T: Grid> Grid for Transpose<T> {
+    type Item = T::Item;
+
+    unsafe fn item_unchecked(self, index: impl Index0D) -> Self::Item {
+        self.grid.item_unchecked(index.unchecked().transpose())
+    }
+}
+
+macro_rules! grid1d {
+    ($($Trait:ident $Assoc:ident $fn:ident ($Inner:ident $InnerAssoc:ident $inner:ident))*) => { $(
+        impl<T: $Inner> $Trait for Transpose<T> {
+            type $Assoc = T::$InnerAssoc;
+
+            unsafe fn $fn(self, index: impl Index1D) -> Self::$Assoc {
+                let index = index.$fn(self.size());
+
+                self.grid.$inner(index)
+            }
+        }
+    )* };
+}
+
+macro_rules! grid2d {
+    ($($Trait:ident $Assoc:ident $fn:ident ($Inner:ident $InnerAssoc:ident $inner:ident))*) => { $(
+        impl<T: $Inner> $Trait for Transpose<T> {
+            type $Assoc = T::$InnerAssoc;
+
+            unsafe fn $fn(self, index: impl Index2D) -> Self::$Assoc {
+                let index = index.unchecked(self.size()).transpose();
+
+                self.grid.$inner(index)
+            }
+        }
+    )* };
+}
+
+grid1d!(
+    GridCol Col col_unchecked (GridRow Row row_unchecked)
+    GridRow Row row_unchecked (GridCol Col col_unchecked)
+);
+
+grid2d!(
+    GridCols  Cols  cols_unchecked  (GridRows  Rows  rows_unchecked)
+    GridRows  Rows  rows_unchecked  (GridCols  Cols  cols_unchecked)
+    GridItems Items items_unchecked (GridItems Items items_unchecked)
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    fn lines<'a, L, R>(lines: L) -> Vec<Vec<i32>>
+    where
+        L: IntoIterator<Item = R>,
+        R: IntoIterator<Item = &'a i32>,
+    {
+        lines
+            .into_iter()
+            .map(|line| line.into_iter().copied().collect())
+            .collect()
+    }
+
+    #[rustfmt::skip]
+    fn expected() -> RowVec1D<i32> {
+        RowVec1D::new((2, 3), vec![
+            1, 2,
+            3, 4,
+            5, 6,
+        ]).unwrap()
+    }
+
+    fn transposed() -> ColVec1D<i32> {
+        ColVec1D::new((3, 2), vec![1, 2, 3, 4, 5, 6]).unwrap()
+    }
+
+    #[test]
+    fn items() {
+        let (grid, expected) = (transposed(), expected());
+        let grid = (&grid).transpose();
+
+        assert_eq!(grid.size(), Size { x: 2, y: 3 });
+        for x in 0..3 {
+            for y in 0..4 {
+                assert_eq!(grid.item((x, y)), (&expected).item((x, y)));
+            }
+        }
+    }
+
+    #[test]
+    fn lines_and_ranges() {
+        let (grid, expected) = (transposed(), expected());
+        let (grid, expected) = ((&grid).transpose(), &expected);
+
+        for i in 0..2 {
+            assert_eq!(lines(grid.col(i)), lines(expected.col(i)));
+        }
+        for i in 0..3 {
+            assert_eq!(lines(grid.row(i)), lines(expected.row(i)));
+        }
+        assert_eq!(lines(grid.row((2, 1..2))), [vec![6]]);
+        assert_eq!(lines(grid.col((0, 1..3))), [vec![3, 5]]);
+
+        assert_eq!(lines(grid.rows(..).unwrap()), [[1, 2], [3, 4], [5, 6]]);
+        assert_eq!(lines(grid.cols(..).unwrap()), [[1, 3, 5], [2, 4, 6]]);
+        assert_eq!(lines(grid.rows((1..2, 1..3)).unwrap()), [[4], [6]]);
+        assert_eq!(lines(grid.cols((0..2, 1..2)).unwrap()), [[3], [4]]);
+        assert!(grid.rows((0..3, 0..1)).is_none());
+    }
+}
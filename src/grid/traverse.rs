@@ -0,0 +1,769 @@
+//! Alternative traversal orders: borders, rings, diagonals, spirals and
+//! Z-order.
+//!
+//! [`Traverse`] yields the items of a grid with their [`Point`], in the order
+//! of an iterator over [`Point`]s such as [`Border`], [`Diagonal`],
+//! [`Spiral`] or [`Morton`].
+
+use crate::grid::*;
+use std::{iter::FusedIterator, ops::Range};
+
+// ----------------------------------------------------------- //
+//                                                             //
+// ************************ TRAVERSE ************************* //
+//                                                             //
+// ----------------------------------------------------------- //
+
+/// An iterator over the items of a grid with their [`Point`], in the order
+/// of `O`.
+///
+/// See [`Grid::border()`], [`Grid::spiral()`], [`Grid::morton()`],
+/// [`Grid::rings()`] and [`Grid::diagonals()`].
+#[derive(Clone, Debug)]
+pub struct Traverse<T, O> {
+    grid:   T,
+    points: O,
+}
+
+impl<T, O> Traverse<T, O> {
+    /// Creates a new [`Traverse`].
+    ///
+    /// ### Safety
+    ///
+    /// `points` must only yield points within `grid`.
+    pub(crate) unsafe fn new_unchecked(grid: T, points: O) -> Self {
+        Self { grid, points }
+    }
+
+    /// Returns the iterator over the remaining [`Point`]s.
+    pub fn points(&self) -> &O {
+        &self.points
+    }
+}
+
+impl<T, O> Iterator for Traverse<T, O>
+where
+    T: Grid + Clone,
+    O: Iterator<Item = Point>,
+{
+    type Item = (Point, T::Item);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let point = self.points.next()?;
+
+        // SAFETY: points are within the grid
+        Some((point, unsafe { self.grid.clone().item_unchecked(point) }))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.points.size_hint()
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        let point = self.points.nth(n)?;
+
+        // SAFETY: points are within the grid
+        Some((point, unsafe { self.grid.clone().item_unchecked(point) }))
+    }
+}
+
+impl<T, O> DoubleEndedIterator for Traverse<T, O>
+where
+    T: Grid + Clone,
+    O: DoubleEndedIterator<Item = Point>,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let point = self.points.next_back()?;
+
+        // SAFETY: points are within the grid
+        Some((point, unsafe { self.grid.clone().item_unchecked(point) }))
+    }
+}
+
+impl<T, O> ExactSizeIterator for Traverse<T, O>
+where
+    T: Grid + Clone,
+    O: ExactSizeIterator<Item = Point>,
+{
+}
+
+impl<T, O> FusedIterator for Traverse<T, O>
+where
+    T: Grid + Clone,
+    O: FusedIterator<Item = Point>,
+{
+}
+
+// ----------------------------------------------------------- //
+//                                                             //
+// ************************* BORDER ************************** //
+//                                                             //
+// ----------------------------------------------------------- //
+
+/// An iterator over the [`Point`]s on the perimeter of a [`Rect`].
+///
+/// Points are yielded clockwise, starting at the top left corner.
+///
+/// See [`Grid::border()`].
+#[derive(Clone, Debug)]
+pub struct Border {
+    rect:  Rect,
+    front: usize,
+    back:  usize,
+}
+
+impl Border {
+    /// Creates a new [`Border`] around `rect`.
+    pub fn new(rect: Rect) -> Self {
+        let Size { x, y } = rect.size();
+        let len = if x == 0 || y == 0 {
+            0
+        } else if x == 1 || y == 1 {
+            x * y
+        } else {
+            2 * (x + y) - 4
+        };
+
+        Self {
+            rect,
+            front: 0,
+            back: len,
+        }
+    }
+
+    /// Returns the `i`th point of the perimeter.
+    fn point(&self, mut i: usize) -> Point {
+        let Rect { x, y } = &self.rect;
+        let (width, height) = (x.end - x.start, y.end - y.start);
+
+        if height == 1 {
+            return Point { x: x.start + i, y: y.start };
+        }
+        if width == 1 {
+            return Point { x: x.start, y: y.start + i };
+        }
+
+        // Top, left to right
+        if i < width {
+            return Point { x: x.start + i, y: y.start };
+        }
+        i -= width;
+
+        // Right, top to bottom
+        if i < height - 1 {
+            return Point { x: x.end - 1, y: y.start + 1 + i };
+        }
+        i -= height - 1;
+
+        // Bottom, right to left
+        if i < width - 1 {
+            return Point { x: x.end - 2 - i, y: y.end - 1 };
+        }
+        i -= width - 1;
+
+        // Left, bottom to top
+        Point { x: x.start, y: y.end - 2 - i }
+    }
+}
+
+impl Iterator for Border {
+    type Item = Point;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.nth(0)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.back - self.front;
+
+        (len, Some(len))
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        if n < self.back - self.front {
+            self.front += n + 1;
+
+            Some(self.point(self.front - 1))
+        } else {
+            self.front = self.back;
+
+            None
+        }
+    }
+}
+
+impl DoubleEndedIterator for Border {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.nth_back(0)
+    }
+
+    fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+        if n < self.back - self.front {
+            self.back -= n + 1;
+
+            Some(self.point(self.back))
+        } else {
+            self.back = self.front;
+
+            None
+        }
+    }
+}
+
+impl ExactSizeIterator for Border {}
+
+impl FusedIterator for Border {}
+
+// ----------------------------------------------------------- //
+//                                                             //
+// ************************** RINGS ************************** //
+//                                                             //
+// ----------------------------------------------------------- //
+
+/// An iterator over the concentric rings of a grid, from the outermost to the
+/// innermost.
+///
+/// Each ring is a [`Traverse`] over a [`Border`].
+///
+/// See [`Grid::rings()`].
+#[derive(Clone, Debug)]
+pub struct Rings<T> {
+    grid: T,
+    rect: Rect,
+}
+
+impl<T: WithSize> Rings<T> {
+    pub(crate) fn new(grid: T, rect: impl Index2D) -> Option<Self> {
+        let rect = rect.checked(grid.size())?;
+
+        Some(Self { grid, rect })
+    }
+}
+
+impl<T: Grid + Clone> Iterator for Rings<T> {
+    type Item = Traverse<T, Border>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.rect.is_empty() {
+            return None;
+        }
+
+        let border = Border::new(self.rect.clone());
+        self.rect = self.rect.inset(1);
+
+        // SAFETY: the rect is within the grid
+        Some(unsafe { Traverse::new_unchecked(self.grid.clone(), border) })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let Size { x, y } = self.rect.size();
+        let len = x.min(y).div_ceil(2);
+
+        (len, Some(len))
+    }
+}
+
+impl<T: Grid + Clone> ExactSizeIterator for Rings<T> {}
+
+impl<T: Grid + Clone> FusedIterator for Rings<T> {}
+
+// ----------------------------------------------------------- //
+//                                                             //
+// ************************* SPIRAL ************************** //
+//                                                             //
+// ----------------------------------------------------------- //
+
+/// An iterator over the [`Point`]s of a [`Rect`], spiraling clockwise from
+/// the top left corner to the center.
+///
+/// See [`Grid::spiral()`].
+#[derive(Clone, Debug)]
+pub struct Spiral {
+    rect:   Rect,
+    border: Border,
+    len:    usize,
+}
+
+impl Spiral {
+    /// Creates a new [`Spiral`] over `rect`.
+    pub fn new(rect: Rect) -> Self {
+        let Size { x, y } = rect.size();
+
+        Self {
+            border: Border::new(rect.clone()),
+            rect,
+            len: x * y,
+        }
+    }
+}
+
+impl Iterator for Spiral {
+    type Item = Point;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.len == 0 {
+            return None;
+        }
+
+        loop {
+            if let Some(point) = self.border.next() {
+                self.len -= 1;
+
+                return Some(point);
+            }
+
+            self.rect = self.rect.inset(1);
+            self.border = Border::new(self.rect.clone());
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl ExactSizeIterator for Spiral {}
+
+impl FusedIterator for Spiral {}
+
+// ----------------------------------------------------------- //
+//                                                             //
+// ************************ DIAGONALS ************************ //
+//                                                             //
+// ----------------------------------------------------------- //
+
+/// An iterator over the [`Point`]s of a diagonal, going down right, or down
+/// left for anti-diagonals.
+///
+/// See [`Grid::diagonals()`] and [`Grid::anti_diagonals()`].
+#[derive(Clone, Debug)]
+pub struct Diagonal {
+    start: Point,
+    anti:  bool,
+    range: Range<usize>,
+}
+
+impl Diagonal {
+    fn point(&self, i: usize) -> Point {
+        let x = if self.anti {
+            self.start.x - i
+        } else {
+            self.start.x + i
+        };
+
+        Point {
+            x,
+            y: self.start.y + i,
+        }
+    }
+}
+
+impl Iterator for Diagonal {
+    type Item = Point;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.range.next().map(|i| self.point(i))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.range.size_hint()
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.range.nth(n).map(|i| self.point(i))
+    }
+}
+
+impl DoubleEndedIterator for Diagonal {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.range.next_back().map(|i| self.point(i))
+    }
+
+    fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+        self.range.nth_back(n).map(|i| self.point(i))
+    }
+}
+
+impl ExactSizeIterator for Diagonal {}
+
+impl FusedIterator for Diagonal {}
+
+/// An iterator over the diagonals (or anti-diagonals) of a grid.
+///
+/// Diagonals go down right and are yielded from the bottom left corner to the
+/// top right corner. Anti-diagonals go down left and are yielded from the top
+/// left corner to the bottom right corner. Each diagonal is a [`Traverse`]
+/// over a [`Diagonal`].
+///
+/// See [`Grid::diagonals()`] and [`Grid::anti_diagonals()`].
+#[derive(Clone, Debug)]
+pub struct Diagonals<T> {
+    grid:  T,
+    rect:  Rect,
+    anti:  bool,
+    range: Range<usize>,
+}
+
+impl<T: WithSize> Diagonals<T> {
+    pub(crate) fn new(grid: T, rect: impl Index2D, anti: bool) -> Option<Self> {
+        let rect = rect.checked(grid.size())?;
+        let len = if rect.is_empty() {
+            0
+        } else {
+            let Size { x, y } = rect.size();
+            x + y - 1
+        };
+
+        Some(Self {
+            grid,
+            rect,
+            anti,
+            range: 0..len,
+        })
+    }
+}
+
+impl<T: Grid + Clone> Diagonals<T> {
+    /// Returns the `i`th diagonal.
+    fn diagonal(&self, i: usize) -> Traverse<T, Diagonal> {
+        let Rect { x, y } = &self.rect;
+        let (width, height) = (x.end - x.start, y.end - y.start);
+
+        let (start, len) = if self.anti {
+            if i < width {
+                (Point { x: x.start + i, y: y.start }, (i + 1).min(height))
+            } else {
+                let i = i - (width - 1);
+                (Point { x: x.end - 1, y: y.start + i }, (height - i).min(width))
+            }
+        } else if i < height {
+            (Point { x: x.start, y: y.end - 1 - i }, (i + 1).min(width))
+        } else {
+            let i = i - (height - 1);
+            (Point { x: x.start + i, y: y.start }, (width - i).min(height))
+        };
+
+        let diagonal = Diagonal {
+            start,
+            anti: self.anti,
+            range: 0..len,
+        };
+
+        // SAFETY: the diagonal is within the rect, which is within the grid
+        unsafe { Traverse::new_unchecked(self.grid.clone(), diagonal) }
+    }
+}
+
+impl<T: Grid + Clone> Iterator for Diagonals<T> {
+    type Item = Traverse<T, Diagonal>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.range.next().map(|i| self.diagonal(i))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.range.size_hint()
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.range.nth(n).map(|i| self.diagonal(i))
+    }
+}
+
+impl<T: Grid + Clone> DoubleEndedIterator for Diagonals<T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.range.next_back().map(|i| self.diagonal(i))
+    }
+
+    fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+        self.range.nth_back(n).map(|i| self.diagonal(i))
+    }
+}
+
+impl<T: Grid + Clone> ExactSizeIterator for Diagonals<T> {}
+
+impl<T: Grid + Clone> FusedIterator for Diagonals<T> {}
+
+// ----------------------------------------------------------- //
+//                                                             //
+// ************************* MORTON ************************** //
+//                                                             //
+// ----------------------------------------------------------- //
+
+/// An iterator over the [`Point`]s of a [`Rect`] in Z-order (Morton order).
+///
+/// Bits of `x` and `y` are interleaved, `x` first, as long as both axes have
+/// bits left; the remaining bits of the longest axis come last. Points
+/// outside of the [`Rect`] are skipped, which costs at most 3 skipped codes
+/// per yielded point.
+///
+/// See [`Grid::morton()`].
+#[derive(Clone, Debug)]
+pub struct Morton {
+    start: Point,
+    size:  Size,
+    bits:  Size<u32>,
+    codes: Range<usize>,
+}
+
+impl Morton {
+    /// Creates a new [`Morton`] over `rect`, or [`None`] if its codes do not
+    /// fit in a `usize`.
+    pub fn new(rect: Rect) -> Option<Self> {
+        fn bits(len: usize) -> u32 {
+            usize::BITS - len.saturating_sub(1).leading_zeros()
+        }
+
+        let size = rect.size();
+        let bits = Size {
+            x: bits(size.x),
+            y: bits(size.y),
+        };
+        let end = if rect.is_empty() {
+            0
+        } else {
+            1usize.checked_shl(bits.x + bits.y)?
+        };
+
+        Some(Self {
+            start: rect.start(),
+            size,
+            bits,
+            codes: 0..end,
+        })
+    }
+
+    /// Returns the point of `code`, relative to the start of the rect.
+    fn decode(&self, code: usize) -> Point {
+        let common = self.bits.x.min(self.bits.y);
+        let mut point = Point { x: 0, y: 0 };
+
+        for i in 0..common {
+            point.x |= (code >> (2 * i) & 1) << i;
+            point.y |= (code >> (2 * i + 1) & 1) << i;
+        }
+
+        let rest = code >> (2 * common) << common;
+        if self.bits.x > self.bits.y {
+            point.x |= rest;
+        } else {
+            point.y |= rest;
+        }
+
+        point
+    }
+
+    /// Returns the absolute point of `code`, if within the rect.
+    fn point(&self, code: usize) -> Option<Point> {
+        let point = self.decode(code);
+
+        (point.x < self.size.x && point.y < self.size.y).then(|| self.start + point)
+    }
+}
+
+impl Iterator for Morton {
+    type Item = Point;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some(code) = self.codes.next() {
+            if let Some(point) = self.point(code) {
+                return Some(point);
+            }
+        }
+
+        None
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.codes.len().min(self.size.x.saturating_mul(self.size.y));
+
+        (0, Some(len))
+    }
+}
+
+impl DoubleEndedIterator for Morton {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        while let Some(code) = self.codes.next_back() {
+            if let Some(point) = self.point(code) {
+                return Some(point);
+            }
+        }
+
+        None
+    }
+}
+
+impl FusedIterator for Morton {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+    use std::collections::HashSet;
+
+    /// Rects of various shapes, offset within a 9 * 8 grid.
+    #[rustfmt::skip]
+    fn rects() -> impl Iterator<Item = Rect> {
+        let sizes = vec![
+            (0, 3), (3, 0), (1, 1), (1, 5), (5, 1), (2, 2), (4, 3), (3, 4), (7, 2), (2, 6),
+        ];
+
+        sizes
+            .into_iter()
+            .map(|(x, y)| Point::<usize> { x: 1, y: 2 }.rect(Size { x, y }))
+    }
+
+    fn grid() -> RowVec1D<usize> {
+        RowVec1D::new((9, 8), (0..72).collect()).unwrap()
+    }
+
+    /// Asserts that `points` visit each point of `rect` once, with its item.
+    fn covers<'a>(rect: &Rect, points: impl Iterator<Item = (Point, &'a usize)>) -> Vec<Point> {
+        let points = points
+            .map(|(point, &item)| {
+                assert_eq!(item, point.y * 9 + point.x);
+                point
+            })
+            .collect::<Vec<_>>();
+
+        let unique = points.iter().copied().collect::<HashSet<_>>();
+        assert_eq!(points.len(), unique.len(), "{:?} visits twice", rect);
+        assert_eq!(unique, rect.points().collect(), "{:?} misses points", rect);
+
+        points
+    }
+
+    #[test]
+    fn border() {
+        let grid = grid();
+
+        for rect in rects() {
+            let border = (&grid).border(rect.clone()).unwrap();
+            let len = border.len();
+            let points = border.map(|(point, _)| point).collect::<Vec<_>>();
+            let inner = rect.inset(1);
+
+            assert_eq!(points.len(), len);
+            assert_eq!(points.iter().copied().collect::<HashSet<_>>().len(), len);
+            assert!(points.iter().all(|&point| rect.contains(point) && !inner.contains(point)));
+            assert_eq!(len, rect.points().filter(|&point| !inner.contains(point)).count());
+
+            let mut back = (&grid).border(rect.clone()).unwrap().rev().map(|(point, _)| point);
+            assert!(points.iter().rev().copied().eq(&mut back));
+        }
+
+        let points = (&grid)
+            .border(Point::ZERO.rect(Size { x: 3, y: 2 }))
+            .unwrap()
+            .map(|(point, _)| (point.x, point.y))
+            .collect::<Vec<_>>();
+        assert_eq!(points, [(0, 0), (1, 0), (2, 0), (2, 1), (1, 1), (0, 1)]);
+        assert!((&grid).border((5..10, ..)).is_none());
+    }
+
+    #[test]
+    fn spiral_and_rings() {
+        let grid = grid();
+
+        for rect in rects() {
+            let spiral = (&grid).spiral(rect.clone()).unwrap();
+            assert_eq!(spiral.len(), rect.points().len());
+            let spiral = covers(&rect, spiral);
+
+            let rings = (&grid).rings(rect.clone()).unwrap();
+            let len = rings.len();
+            let rings = rings.collect::<Vec<_>>();
+            assert_eq!(rings.len(), len);
+
+            // Rings are the spiral, ring by ring
+            let flat = rings.into_iter().flatten().map(|(point, _)| point);
+            assert!(flat.eq(spiral));
+        }
+
+        let spiral = (&grid)
+            .spiral(Point::ZERO.rect(Size { x: 4, y: 3 }))
+            .unwrap()
+            .map(|(_, &item)| item)
+            .collect::<Vec<_>>();
+        assert_eq!(spiral, [0, 1, 2, 3, 12, 21, 20, 19, 18, 9, 10, 11]);
+    }
+
+    #[test]
+    fn diagonals() {
+        let grid = grid();
+
+        for rect in rects() {
+            let Size { x, y } = rect.size();
+            let count = if rect.is_empty() { 0 } else { x + y - 1 };
+
+            for anti in [false, true] {
+                let diagonals = if anti {
+                    (&grid).anti_diagonals(rect.clone())
+                } else {
+                    (&grid).diagonals(rect.clone())
+                }
+                .unwrap();
+                assert_eq!(diagonals.len(), count);
+
+                let diagonals = diagonals
+                    .map(|diagonal| diagonal.collect::<Vec<_>>())
+                    .collect::<Vec<_>>();
+                assert_eq!(diagonals.len(), count);
+
+                for (k, diagonal) in diagonals.iter().enumerate() {
+                    for (point, _) in diagonal {
+                        let (dx, dy) = (point.x - rect.x.start, point.y - rect.y.start);
+
+                        if anti {
+                            assert_eq!(dx + dy, k);
+                        } else {
+                            assert_eq!(dx + (y - 1), k + dy);
+                        }
+                    }
+                }
+
+                covers(&rect, diagonals.into_iter().flatten());
+            }
+        }
+
+        let diagonals = (&grid)
+            .diagonals(Point::ZERO.rect(Size { x: 2, y: 3 }))
+            .unwrap()
+            .rev()
+            .map(|diagonal| diagonal.rev().map(|(_, &item)| item).collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+        assert_eq!(diagonals, [vec![1], vec![10, 0], vec![19, 9], vec![18]]);
+    }
+
+    #[test]
+    fn morton() {
+        let grid = grid();
+
+        for rect in rects() {
+            let morton = covers(&rect, (&grid).morton(rect.clone()).unwrap());
+
+            let mut back = (&grid).morton(rect.clone()).unwrap().rev().map(|(point, _)| point);
+            assert!(morton.iter().rev().copied().eq(&mut back));
+        }
+
+        let morton = (&grid)
+            .morton(Point::ZERO.rect(Size { x: 4, y: 2 }))
+            .unwrap()
+            .map(|(_, &item)| item)
+            .collect::<Vec<_>>();
+        assert_eq!(morton, [0, 1, 9, 10, 2, 3, 11, 12]);
+    }
+
+    #[test]
+    fn morton_overflow() {
+        let grid = repeat((usize::MAX, usize::MAX), ());
+
+        assert!((&grid).morton(..).is_none());
+        assert!((&grid).morton((.., ..1)).is_none());
+        assert!((&grid).morton((..1 << 40, ..1 << 30)).is_none());
+        assert!((&grid).morton((..1 << 40, ..1 << 20)).is_some());
+
+        let mut morton = (&grid).morton((1..1 << 40, 3..4)).unwrap();
+        assert_eq!(morton.next().map(|(point, _)| point), Some(Point { x: 1, y: 3 }));
+    }
+}
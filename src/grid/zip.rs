@@ -22,11 +22,9 @@ impl<A: WithSize, B: WithSize> Zip<A, B> {
 }
 
 impl<A: Grid, B: Grid> Zip<Crop<A>, Crop<B>> {
-    pub(crate) fn at(a: A, b: B, position: Point) -> Self {
-        let rect_b = position.rect(b.size());
-        let rect_a = rect_b.crop(a.size());
+    pub(crate) fn at(a: A, b: B, position: Point<isize>) -> Self {
+        let (rect_a, rect_b) = position.overlap(a.size(), b.size());
         let size = rect_a.size();
-        let rect_b = Point::ZERO.rect(size);
 
         // SAFETY: the above guaranties we are in bounds
         debug_assert!(rect_a.clone().checked(a.size()).is_some());
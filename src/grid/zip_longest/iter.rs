@@ -0,0 +1,123 @@
+//! Iterators for [`ZipLongest`](super::ZipLongest)'s `Grid*` implementations.
+
+use super::*;
+
+/// A 1D iterator over [`ZipLongest`](super::ZipLongest)'s column/row.
+#[derive(Clone, Debug)]
+pub struct Iter1D<A, B> {
+    a:       Option<A>,
+    b:       Option<B>,
+    a_range: Range<usize>,
+    b_range: Range<usize>,
+    range:   Range<usize>,
+}
+
+impl<A, B> Iter1D<A, B> {
+    /// `a` and `b` are yielded in `a_range` and `b_range`, within `range`.
+    pub(crate) fn new(
+        a: Option<A>,
+        b: Option<B>,
+        a_range: Range<usize>,
+        b_range: Range<usize>,
+        range: Range<usize>,
+    ) -> Self {
+        Self {
+            a,
+            b,
+            a_range,
+            b_range,
+            range,
+        }
+    }
+}
+
+impl<A: Iterator, B: Iterator> Iterator for Iter1D<A, B> {
+    type Item = (Option<A::Item>, Option<B::Item>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let i = self.range.next()?;
+        let a = next_in(&mut self.a, &self.a_range, i);
+        let b = next_in(&mut self.b, &self.b_range, i);
+
+        Some((a, b))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.range.size_hint()
+    }
+}
+
+/// A 2D iterator over [`ZipLongest`](super::ZipLongest)'s columns/rows.
+#[derive(Clone, Debug)]
+pub struct Iter2D<A, B> {
+    a:       A,
+    b:       B,
+    a_minor: Range<usize>,
+    b_minor: Range<usize>,
+    a_major: Range<usize>,
+    b_major: Range<usize>,
+    minor:   Range<usize>,
+    major:   Range<usize>,
+}
+
+impl<A, B> Iter2D<A, B> {
+    /// `a`'s and `b`'s lines are yielded in `a_minor` and `b_minor`, within
+    /// `minor`. Their items are yielded in `a_major` and `b_major`, within
+    /// `major`.
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn new(
+        a: A,
+        b: B,
+        a_minor: Range<usize>,
+        b_minor: Range<usize>,
+        a_major: Range<usize>,
+        b_major: Range<usize>,
+        minor: Range<usize>,
+        major: Range<usize>,
+    ) -> Self {
+        Self {
+            a,
+            b,
+            a_minor,
+            b_minor,
+            a_major,
+            b_major,
+            minor,
+            major,
+        }
+    }
+}
+
+impl<A: Iterator, B: Iterator> Iterator for Iter2D<A, B>
+where
+    A::Item: IntoIterator,
+    B::Item: IntoIterator,
+{
+    type Item = Iter1D<Iter<A::Item>, Iter<B::Item>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let i = self.minor.next()?;
+        let a = next_in(&mut Some(&mut self.a), &self.a_minor, i);
+        let b = next_in(&mut Some(&mut self.b), &self.b_minor, i);
+
+        Some(Iter1D::new(
+            a.map(IntoIterator::into_iter),
+            b.map(IntoIterator::into_iter),
+            self.a_major.clone(),
+            self.b_major.clone(),
+            self.major.clone(),
+        ))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.minor.size_hint()
+    }
+}
+
+fn next_in<T: Iterator>(iter: &mut Option<T>, range: &Range<usize>, i: usize) -> Option<T::Item> {
+    if range.contains(&i) {
+        iter.as_mut()?.next()
+    } else {
+        None
+    }
+}
@@ -0,0 +1,274 @@
+//! A grid that zips two grids over their union area.
+
+pub mod iter;
+
+use super::*;
+use iter::*;
+use std::{iter::Flatten, ops::Range};
+
+type Iter<T> = <T as IntoIterator>::IntoIter;
+
+/// ‘Zips up’ two grids into a single grid of optional pairs.
+///
+/// Yields elements from the bounding area of both grids, with [`None`] where
+/// a grid has no item.
+///
+/// See [`Grid::zip_longest`], [`Grid::zip_longest_at`].
+#[derive(Clone, Default, Debug)]
+pub struct ZipLongest<A, B> {
+    a:      A,
+    b:      B,
+    rect_a: Rect,
+    rect_b: Rect,
+    size:   Size,
+}
+
+impl<A: WithSize, B: WithSize> ZipLongest<A, B> {
+    pub(crate) fn new(a: A, b: B) -> Self {
+        Self::at(a, b, Point::ZERO.into())
+    }
+
+    /// Zips `b` at `position` relative to `a`, in the bounding area of both
+    /// grids.
+    pub(crate) fn at(a: A, b: B, position: Point<isize>) -> Self {
+        // The bounding area starts at the top left of both grids
+        let start_a = Point {
+            x: position.x.min(0).unsigned_abs(),
+            y: position.y.min(0).unsigned_abs(),
+        };
+        let start_b = Point {
+            x: position.x.max(0) as usize,
+            y: position.y.max(0) as usize,
+        };
+        let rect_a = start_a.rect(a.size());
+        let rect_b = start_b.rect(b.size());
+        let size = Size {
+            x: rect_a.x.end.max(rect_b.x.end),
+            y: rect_a.y.end.max(rect_b.y.end),
+        };
+
+        Self {
+            a,
+            b,
+            rect_a,
+            rect_b,
+            size,
+        }
+    }
+}
+
+impl<A, B> WithSize for ZipLongest<A, B> {
+    fn size(&self) -> Size {
+        self.size
+    }
+}
+
+impl<A: Grid, B: Grid> Grid for ZipLongest<A, B> {
+    type Item = (Option<A::Item>, Option<B::Item>);
+
+    unsafe fn item_unchecked(self, index: impl Index0D) -> Self::Item {
+        let Self {
+            a,
+            b,
+            rect_a,
+            rect_b,
+            ..
+        } = self;
+        let point = index.unchecked();
+        let item = |rect: Rect| {
+            if rect.x.contains(&point.x) && rect.y.contains(&point.y) {
+                Some(point - rect.start())
+            } else {
+                None
+            }
+        };
+
+        (
+            item(rect_a).map(|point| a.item_unchecked(point)),
+            item(rect_b).map(|point| b.item_unchecked(point)),
+        )
+    }
+}
+
+macro_rules! grid1d {
+    ($($Trait:ident $Assoc:ident $fn:ident $i:ident $range:ident)*) => { $(
+        impl<A: $Trait, B: $Trait> $Trait for ZipLongest<A, B> {
+            type $Assoc = Iter1D<Iter<A::$Assoc>, Iter<B::$Assoc>>;
+
+            unsafe fn $fn(self, index: impl Index1D) -> Self::$Assoc {
+                let (i, range) = index.$fn(self.size);
+                let a_range = intersect(&range, &self.rect_a.$range);
+                let b_range = intersect(&range, &self.rect_b.$range);
+                let (a, b) = (self.a, self.b);
+
+                let a = if self.rect_a.$i.contains(&i) {
+                    let index = (
+                        i - self.rect_a.$i.start,
+                        shift(&a_range, self.rect_a.$range.start),
+                    );
+                    Some(a.$fn(index).into_iter())
+                } else {
+                    None
+                };
+                let b = if self.rect_b.$i.contains(&i) {
+                    let index = (
+                        i - self.rect_b.$i.start,
+                        shift(&b_range, self.rect_b.$range.start),
+                    );
+                    Some(b.$fn(index).into_iter())
+                } else {
+                    None
+                };
+
+                Iter1D::new(a, b, a_range, b_range, range)
+            }
+        }
+    )* };
+}
+
+macro_rules! grid2d {
+    ($($Trait:ident $Assoc:ident $fn:ident $minor:ident $major:ident)*) => { $(
+        impl<A: $Trait, B: $Trait> $Trait for ZipLongest<A, B> {
+            type $Assoc = Iter2D<Iter<A::$Assoc>, Iter<B::$Assoc>>;
+
+            unsafe fn $fn(self, index: impl Index2D) -> Self::$Assoc {
+                let rect = index.unchecked(self.size);
+                let a_rect = Rect {
+                    x: intersect(&rect.x, &self.rect_a.x),
+                    y: intersect(&rect.y, &self.rect_a.y),
+                };
+                let b_rect = Rect {
+                    x: intersect(&rect.x, &self.rect_b.x),
+                    y: intersect(&rect.y, &self.rect_b.y),
+                };
+                let a = self.a.$fn(Rect {
+                    x: shift(&a_rect.x, self.rect_a.x.start),
+                    y: shift(&a_rect.y, self.rect_a.y.start),
+                });
+                let b = self.b.$fn(Rect {
+                    x: shift(&b_rect.x, self.rect_b.x.start),
+                    y: shift(&b_rect.y, self.rect_b.y.start),
+                });
+
+                Iter2D::new(
+                    a.into_iter(),
+                    b.into_iter(),
+                    a_rect.$minor,
+                    b_rect.$minor,
+                    a_rect.$major,
+                    b_rect.$major,
+                    rect.$minor,
+                    rect.$major,
+                )
+            }
+        }
+    )* };
+}
+
+grid1d!(
+    GridCol Col col_unchecked x y
+    GridRow Row row_unchecked y x
+);
+
+grid2d!(
+    GridCols Cols cols_unchecked x y
+    GridRows Rows rows_unchecked y x
+);
+
+impl<A: GridRows, B: GridRows> GridItems for ZipLongest<A, B> {
+    type Items = Flatten<<Self as GridRows>::Rows>;
+
+    unsafe fn items_unchecked(self, index: impl Index2D) -> Self::Items {
+        self.rows_unchecked(index).flatten()
+    }
+}
+
+/// Returns the part of `range` in `bounds`, always within `bounds`.
+fn intersect(range: &Range<usize>, bounds: &Range<usize>) -> Range<usize> {
+    let start = range.start.max(bounds.start).min(bounds.end);
+    let end = range.end.min(bounds.end).max(start);
+
+    start..end
+}
+
+fn shift(range: &Range<usize>, by: usize) -> Range<usize> {
+    range.start - by..range.end - by
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    type Pair<'a> = (Option<&'a i32>, Option<&'a i32>);
+
+    fn corners<'a, T: Grid<Item = Pair<'a>> + Clone>(grid: T) -> [Pair<'a>; 4] {
+        let Size { x, y } = grid.size();
+
+        [
+            grid.clone().item((0, 0)).unwrap(),
+            grid.clone().item((x - 1, 0)).unwrap(),
+            grid.clone().item((0, y - 1)).unwrap(),
+            grid.item((x - 1, y - 1)).unwrap(),
+        ]
+    }
+
+    fn grids() -> (RowVec1D<i32>, RowVec1D<i32>) {
+        (
+            RowVec1D::new((2, 2), vec![1, 2, 3, 4]).unwrap(),
+            RowVec1D::new((2, 1), vec![5, 6]).unwrap(),
+        )
+    }
+
+    #[test]
+    fn positive_offset() {
+        let (a, b) = grids();
+        let zip = (&a).zip_longest_at((1, 1), &b);
+
+        assert_eq!(zip.size(), Size { x: 3, y: 2 });
+        assert_eq!(corners(zip.clone()), [
+            (Some(&1), None),
+            (None, None),
+            (Some(&3), None),
+            (None, Some(&6)),
+        ]);
+        assert_eq!(zip.clone().item((1, 1)), Some((Some(&4), Some(&5))));
+
+        let rows = zip.rows(..).unwrap().map(Iterator::collect::<Vec<_>>);
+        assert_eq!(rows.collect::<Vec<_>>(), [
+            vec![(Some(&1), None), (Some(&2), None), (None, None)],
+            vec![(Some(&3), None), (Some(&4), Some(&5)), (None, Some(&6))],
+        ]);
+    }
+
+    #[test]
+    fn zero_offset() {
+        let (a, b) = grids();
+        let zip = (&a).zip_longest_at((0, 0), &b);
+
+        assert_eq!(zip.size(), Size { x: 2, y: 2 });
+        assert_eq!(corners(zip.clone()), [
+            (Some(&1), Some(&5)),
+            (Some(&2), Some(&6)),
+            (Some(&3), None),
+            (Some(&4), None),
+        ]);
+    }
+
+    #[test]
+    fn negative_offset() {
+        let (a, b) = grids();
+        let zip = (&a).zip_longest_at((-1, -2), &b);
+
+        assert_eq!(zip.size(), Size { x: 3, y: 4 });
+        assert_eq!(corners(zip.clone()), [
+            (None, Some(&5)),
+            (None, None),
+            (None, None),
+            (Some(&4), None),
+        ]);
+        assert_eq!(zip.clone().item((1, 0)), Some((None, Some(&6))));
+        assert_eq!(zip.clone().item((1, 2)), Some((Some(&1), None)));
+        assert_eq!(zip.clone().item((3, 0)), None);
+    }
+}